@@ -1,4 +1,4 @@
-use crate::{Direction, HexGrid};
+use crate::{Direction, Hex, HexGrid};
 use crate::{abs_max_3d_point, to_3d_coordinate};
 
 pub struct Values<'a, T> {
@@ -25,21 +25,47 @@ impl<'a, T> Iterator for Keys<'a, T> {
     }
 }
 
-// pub struct ValuesMut<'a, T> {
-//     pub(crate) iter: AroundIteratorMut<'a, T>,
-// }
+pub struct ValuesMut<'a, T> {
+    pub(crate) iter: AroundIteratorMut<'a, T>,
+}
 
-// impl<'a, T> Iterator for ValuesMut<'a, T> {
-//     type Item = &'a mut T;
+impl<'a, T> Iterator for ValuesMut<'a, T> {
+    type Item = &'a mut T;
 
-//     fn next(&mut self) -> Option<Self::Item> {
-//         self.iter.next().map(|(_, v)| v)
-//     }
-// }
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, v)| v)
+    }
+}
 
 #[derive(Debug)]
 pub struct AroundIterator<'a, T> {
     grid: &'a HexGrid<T>,
+    ring: RingCoordinates,
+}
+
+impl<'a, T> Iterator for AroundIterator<'a, T> {
+    type Item = (&'a (i32, i32), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ring
+            .by_ref()
+            .find_map(|(x, y)| self.grid.get_key_value(x, y))
+    }
+}
+
+impl<'a, T> AroundIterator<'a, T> {
+    pub fn new(grid: &'a HexGrid<T>, distance: i32, mid_point: impl Into<Hex>) -> Self {
+        AroundIterator {
+            grid,
+            ring: RingCoordinates::new(distance, mid_point),
+        }
+    }
+}
+
+/// Iterates over every coordinate on the ring at exactly `distance` of the mid point,
+/// regardless of whether a grid has an item there. [`AroundIterator`] walks this ring.
+#[derive(Debug, Clone)]
+pub struct RingCoordinates {
     distance: i32,
     mid_point_x: i32,
     mid_point_y: i32,
@@ -51,15 +77,20 @@ pub struct AroundIterator<'a, T> {
     previous_direction: Direction,
 }
 
-impl<'a, T> Iterator for AroundIterator<'a, T> {
-    type Item = (&'a (i32, i32), &'a T);
+impl Iterator for RingCoordinates {
+    type Item = (i32, i32);
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.done {
             return None;
         }
 
-        let current_item = self.grid.get_key_value(self.current_x, self.current_y);
+        let current = (self.current_x, self.current_y);
+        if self.distance == 0 {
+            // the ring around a point at distance 0 is only the point itself
+            self.done = true;
+            return Some(current);
+        }
         // dbg!((self.current_x, self.current_y));
         let (next_x, next_y) = self.determine_next();
         // dbg!((next_x, next_y));
@@ -69,28 +100,24 @@ impl<'a, T> Iterator for AroundIterator<'a, T> {
         self.current_x = next_x;
         self.current_y = next_y;
 
-        if let Some(item) = current_item {
-            return Some(item);
-        }
-
-        self.next()
+        Some(current)
     }
 }
 
-impl<'a, T> AroundIterator<'a, T> {
-    pub fn new(grid: &'a HexGrid<T>, distance: i32, mid_point: (i32, i32)) -> Self {
-        let start_point = determine_start(distance, mid_point.0, mid_point.1);
+impl RingCoordinates {
+    pub fn new(distance: i32, mid_point: impl Into<Hex>) -> Self {
+        let mid_point = mid_point.into();
+        let start_point = determine_start(distance, mid_point.q, mid_point.r);
 
-        AroundIterator {
-            grid,
+        RingCoordinates {
             distance,
-            mid_point_x: mid_point.0,
-            mid_point_y: mid_point.1,
+            mid_point_x: mid_point.q,
+            mid_point_y: mid_point.r,
             start_x: start_point.0,
             start_y: start_point.1,
             current_x: start_point.0,
             current_y: start_point.1,
-            done: false,
+            done: distance < 0,
             previous_direction: Direction::Right,
         }
     }
@@ -104,7 +131,7 @@ impl<'a, T> AroundIterator<'a, T> {
         if let Some(might_be_next) = self.previous_direction.apply_next(coord.0, coord.1) {
             let oke = to_3d_coordinate(might_be_next.0, might_be_next.1);
             if abs_max_3d_point(oke) > self.distance {
-                self.previous_direction = self.previous_direction.rotate_clockwise();
+                self.previous_direction = self.previous_direction.rotate_cw(1);
                 return self.determine_next();
             }
 
@@ -114,27 +141,50 @@ impl<'a, T> AroundIterator<'a, T> {
             )
         } else {
             // probably not what you want
-            self.previous_direction = self.previous_direction.rotate_clockwise();
-            return self.determine_next();
+            self.previous_direction = self.previous_direction.rotate_cw(1);
+            self.determine_next()
         }
     }
 }
 
+/// Mutable version of [`AroundIterator`], built on [`HexGrid::get_many_mut`] so making it walks
+/// every cell in the columns the ring spans.
+pub struct AroundIteratorMut<'a, T> {
+    iter: std::vec::IntoIter<((i32, i32), &'a mut T)>,
+}
+
+impl<'a, T> AroundIteratorMut<'a, T> {
+    pub fn new(grid: &'a mut HexGrid<T>, distance: i32, mid_point: impl Into<Hex>) -> Self {
+        AroundIteratorMut {
+            iter: grid
+                .get_many_mut(RingCoordinates::new(distance, mid_point))
+                .into_iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for AroundIteratorMut<'a, T> {
+    type Item = ((i32, i32), &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
 fn determine_start(distance: i32, mid_point_x: i32, mid_point_y: i32) -> (i32, i32) {
     (mid_point_x + distance, mid_point_y)
 }
 
 #[test]
 fn determine_next_test() {
-    fn do_it(iter: &mut AroundIterator<i32>) -> (i32, i32) {
+    fn do_it(iter: &mut RingCoordinates) -> (i32, i32) {
         let next = iter.determine_next();
         iter.current_x = next.0;
         iter.current_y = next.1;
-        return next;
+        next
     }
 
-    let grid = HexGrid::<i32>::new();
-    let mut iter = AroundIterator::new(&grid, 2, (0, 0));
+    let mut iter = RingCoordinates::new(2, (0, 0));
 
     // loops successfull with distance 2
     assert_eq!(do_it(&mut iter), (1, 1));
@@ -184,4 +234,69 @@ fn around_iterator_test() {
     let iter_c = AroundIterator::new(&grid, 1, (1, 0));
     let out: Vec<_> = iter_c.collect();
     assert_eq!(out, vec![(&(2, 0), &"C"), (&(0, 1), &"D"), (&(0, 0), &"A")]);
+
+    let iter_d = AroundIterator::new(&grid, 0, (1, 0));
+    let out: Vec<_> = iter_d.collect();
+    assert_eq!(out, vec![(&(1, 0), &"B")]);
+
+    let iter_e = AroundIterator::new(&grid, 0, (1, 1));
+    assert_eq!(iter_e.count(), 0);
+}
+
+#[test]
+fn ring_coordinates_test() {
+    let out: Vec<_> = RingCoordinates::new(1, (1, -2)).collect();
+    assert_eq!(
+        out,
+        vec![(2, -2), (1, -1), (0, -1), (0, -2), (1, -3), (2, -3)]
+    );
+
+    for distance in 1..4 {
+        assert_eq!(
+            RingCoordinates::new(distance, (1, -2)).count() as i32,
+            6 * distance
+        );
+        assert!(
+            RingCoordinates::new(distance, (1, -2))
+                .all(|point| HexGrid::<()>::distance(point, (1, -2)) == distance)
+        );
+    }
+
+    let out: Vec<_> = RingCoordinates::new(0, (1, -2)).collect();
+    assert_eq!(out, vec![(1, -2)]);
+    assert_eq!(RingCoordinates::new(-1, (1, -2)).count(), 0);
+}
+
+#[test]
+fn around_iterator_mut_test() {
+    //
+    //     A B C
+    //      D
+    //   F E
+    let mut grid = HexGrid::from(btree! {
+        (0, 0) => 1,
+        (1, 0) => 2,
+        (2, 0) => 3,
+        (0, 1) => 4,
+        (-1, 2) => 5,
+        (-2, 2) => 6,
+    });
+
+    let mut keys = Vec::new();
+    for (key, value) in AroundIteratorMut::new(&mut grid, 2, (0, 0)) {
+        *value *= 10;
+        keys.push(key);
+    }
+
+    let expected = HexGrid::from(btree! {
+        (0, 0) => 1,
+        (1, 0) => 2,
+        (2, 0) => 30,
+        (0, 1) => 4,
+        (-1, 2) => 50,
+        (-2, 2) => 60,
+    });
+
+    assert_eq!(expected, grid);
+    assert_eq!(vec![(2, 0), (-1, 2), (-2, 2)], keys);
 }
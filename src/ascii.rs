@@ -0,0 +1,406 @@
+use crate::{HexGrid, OffsetCoord, OffsetLayout, Orientation};
+use std::fmt;
+
+/// the outline of a pointy hex, the label goes on the third line between the `|`.
+#[rustfmt::skip]
+const POINTY_CELL: [&str; 6] = [
+    "  / \\  ",
+    " /   \\ ",
+    "|     |",
+    "|     |",
+    " \\   / ",
+    "  \\ /  ",
+];
+
+/// the outline of a flat hex, the label goes on the third line between the `/` and `\`.
+const FLAT_CELL: [&str; 5] = [
+    "   ___   ",
+    " /     \\ ",
+    "/       \\",
+    "\\       /",
+    " \\_____/ ",
+];
+
+/// draws the grid in the style of the diagrams on [`HexGrid`], with the text from `label` in
+/// every cell that has an item.
+///
+/// Labels are centered and cut off at the width of a cell, 5 characters for pointy hexes and
+/// 7 for flat hexes. Cells without an item are left blank, only the edges they share with
+/// cells that do have an item are drawn. Trailing spaces are trimmed from every line.
+pub fn to_ascii<T, F>(hexgrid: &HexGrid<T>, orientation: Orientation, mut label: F) -> String
+where
+    F: FnMut(&T) -> String,
+{
+    let (cell, label_width): (&[&str], usize) = match orientation {
+        Orientation::Pointy => (&POINTY_CELL, 5),
+        Orientation::Flat => (&FLAT_CELL, 7),
+    };
+
+    // the position of every cell in half hex steps, like doubled coordinates
+    let positions: Vec<_> = hexgrid
+        .iter()
+        .map(|(&(q, r), item)| {
+            let position = match orientation {
+                Orientation::Pointy => (2 * q as i64 + r as i64, r as i64),
+                Orientation::Flat => (q as i64, 2 * r as i64 + q as i64),
+            };
+            (position, item)
+        })
+        .collect();
+
+    let Some(min_x) = positions.iter().map(|((x, _), _)| *x).min() else {
+        return String::new();
+    };
+    let min_y = positions.iter().map(|((_, y), _)| *y).min().unwrap_or(0);
+
+    // how many characters and lines one step in the position moves a cell
+    let (step_x, step_y) = match orientation {
+        Orientation::Pointy => (3, 4),
+        Orientation::Flat => (7, 2),
+    };
+
+    let mut canvas: Vec<Vec<char>> = Vec::new();
+    for ((x, y), item) in positions {
+        let left = ((x - min_x) * step_x) as usize;
+        let top = ((y - min_y) * step_y) as usize;
+
+        for (line, row) in cell.iter().enumerate() {
+            for (column, character) in row.chars().enumerate() {
+                if character != ' ' {
+                    paint(&mut canvas, left + column, top + line, character);
+                }
+            }
+        }
+
+        let text: Vec<char> = label(item).chars().take(label_width).collect();
+        let padding = (label_width - text.len()).div_ceil(2);
+        for (column, character) in text.into_iter().enumerate() {
+            paint(&mut canvas, left + 1 + padding + column, top + 2, character);
+        }
+    }
+
+    canvas
+        .into_iter()
+        .map(|line| line.into_iter().collect::<String>().trim_end().to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// reads a map from text with one character per hex, the opposite of a very small [`to_ascii`].
+///
+/// Cells are separated by a space and every second row is indented by one more space than the
+/// first row, which lays the rows out like [`OffsetLayout::OddR`]. The first character of the
+/// first row ends up at `(0, 0)`. Blank lines before and after the map and trailing spaces are
+/// ignored. `cell` turns a character into an item, or into `None` to leave that hex out of the grid.
+/// # Examples
+/// ```
+/// use hex_grid::{HexGrid, ascii};
+/// let grid = ascii::parse_ascii(
+///     "
+///     . . #
+///      . # .
+///     ",
+///     |character| (character != '#').then_some(character),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(grid.len(), 4);
+/// assert_eq!(grid.get(1, 0), Some(&'.'));
+/// assert_eq!(grid.get(2, 0), None);
+/// assert_eq!(grid.get(2, 1), Some(&'.'));
+/// ```
+pub fn parse_ascii<T, F>(text: &str, mut cell: F) -> Result<HexGrid<T>, ParseAsciiError>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    let lines: Vec<_> = text.lines().enumerate().collect();
+    let first = lines.iter().position(|(_, line)| !line.trim().is_empty());
+    let last = lines.iter().rposition(|(_, line)| !line.trim().is_empty());
+    let (Some(first), Some(last)) = (first, last) else {
+        return Ok(HexGrid::new());
+    };
+
+    let indent_of = |line: &str| line.len() - line.trim_start_matches(' ').len();
+    let base_indent = indent_of(lines[first].1);
+    let mut width = None;
+    let mut grid = HexGrid::new();
+
+    for (row, (index, line)) in lines[first..=last].iter().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim_end();
+
+        let expected_indent = base_indent + row % 2;
+        let indent = indent_of(line);
+        if indent != expected_indent {
+            return Err(ParseAsciiError::BadIndentation {
+                line: line_number,
+                expected: expected_indent,
+                found: indent,
+            });
+        }
+
+        let mut cells = 0;
+        for (offset, character) in line.chars().skip(indent).enumerate() {
+            if offset % 2 == 1 {
+                if character != ' ' {
+                    return Err(ParseAsciiError::Misaligned {
+                        line: line_number,
+                        column: indent + offset + 1,
+                    });
+                }
+                continue;
+            }
+            if character == ' ' {
+                return Err(ParseAsciiError::Misaligned {
+                    line: line_number,
+                    column: indent + offset + 1,
+                });
+            }
+
+            let hex = OffsetCoord::new(cells, row as i32).to_hex(OffsetLayout::OddR);
+            if let Some(item) = cell(character) {
+                grid.insert(hex, item);
+            }
+            cells += 1;
+        }
+
+        let expected = *width.get_or_insert(cells);
+        if cells != expected {
+            return Err(ParseAsciiError::Ragged {
+                line: line_number,
+                expected: expected as usize,
+                found: cells as usize,
+            });
+        }
+    }
+
+    Ok(grid)
+}
+
+/// returned by [`parse_ascii`] when the text isn't laid out as staggered rows,
+/// lines and columns start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseAsciiError {
+    /// a row is not indented by the same amount as the first row, or by one more for every second row.
+    BadIndentation {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// two cells are not separated by exactly one space.
+    Misaligned { line: usize, column: usize },
+    /// a row has a different number of cells than the first row.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseAsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseAsciiError::BadIndentation {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} is indented by {found} spaces, expected {expected}"
+            ),
+            ParseAsciiError::Misaligned { line, column } => write!(
+                f,
+                "line {line} column {column} is not aligned, cells must be separated by one space"
+            ),
+            ParseAsciiError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} cells, expected {expected} like the first row"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseAsciiError {}
+
+fn paint(canvas: &mut Vec<Vec<char>>, x: usize, y: usize, character: char) {
+    if canvas.len() <= y {
+        canvas.resize(y + 1, Vec::new());
+    }
+    let line = &mut canvas[y];
+    if line.len() <= x {
+        line.resize(x + 1, ' ');
+    }
+    line[x] = character;
+}
+
+#[cfg(test)]
+fn coordinate_grid(points: impl IntoIterator<Item = (i32, i32)>) -> HexGrid<String> {
+    points
+        .into_iter()
+        .map(|(q, r)| ((q, r), format!("{q},{r}")))
+        .collect()
+}
+
+#[test]
+fn pointy_diagram_test() {
+    // the same as the diagram at the top of lib.rs
+    let grid = coordinate_grid(
+        [(5, 1), (5, 2), (4, 3), (4, 4), (3, 5)]
+            .into_iter()
+            .flat_map(|(q, r)| (q..q + 4).map(move |q| (q, r))),
+    );
+
+    let expected = [
+        r"  / \   / \   / \   / \",
+        r" /   \ /   \ /   \ /   \",
+        r"| 5,1 | 6,1 | 7,1 | 8,1 |",
+        r"|     |     |     |     |",
+        r" \   / \   / \   / \   / \",
+        r"  \ /   \ /   \ /   \ /   \",
+        r"   | 5,2 | 6,2 | 7,2 | 8,2 |",
+        r"   |     |     |     |     |",
+        r"  / \   / \   / \   / \   /",
+        r" /   \ /   \ /   \ /   \ /",
+        r"| 4,3 | 5,3 | 6,3 | 7,3 |",
+        r"|     |     |     |     |",
+        r" \   / \   / \   / \   / \",
+        r"  \ /   \ /   \ /   \ /   \",
+        r"   | 4,4 | 5,4 | 6,4 | 7,4 |",
+        r"   |     |     |     |     |",
+        r"  / \   / \   / \   / \   /",
+        r" /   \ /   \ /   \ /   \ /",
+        r"| 3,5 | 4,5 | 5,5 | 6,5 |",
+        r"|     |     |     |     |",
+        r" \   / \   / \   / \   /",
+        r"  \ /   \ /   \ /   \ /",
+    ]
+    .join("\n");
+
+    assert_eq!(to_ascii(&grid, Orientation::Pointy, Clone::clone), expected);
+}
+
+#[test]
+fn flat_diagram_test() {
+    // the diagram at the top of lib.rs, but with the bottom of every cell drawn
+    let grid = coordinate_grid((0..2).flat_map(|column| {
+        [(3, 9), (4, 9), (5, 8)]
+            .into_iter()
+            .map(move |(q, r)| (q, r + column))
+    }));
+
+    let expected = [
+        r"   ___           ___",
+        r" /     \       /     \",
+        r"/  3,9  \ ___ /  5,8  \",
+        r"\       /     \       /",
+        r" \_____/  4,9  \_____/",
+        r" /     \       /     \",
+        r"/  3,10 \_____/  5,9  \",
+        r"\       /     \       /",
+        r" \_____/  4,10 \_____/",
+        r"       \       /",
+        r"        \_____/",
+    ]
+    .join("\n");
+
+    assert_eq!(to_ascii(&grid, Orientation::Flat, Clone::clone), expected);
+}
+
+#[test]
+fn labels_and_gaps_test() {
+    let grid: HexGrid<&str> = [((0, 0), "forest"), ((2, 0), "x")].into_iter().collect();
+
+    let expected = [
+        r"  / \         / \",
+        r" /   \       /   \",
+        r"|fores|     |  x  |",
+        r"|     |     |     |",
+        r" \   /       \   /",
+        r"  \ /         \ /",
+    ]
+    .join("\n");
+    assert_eq!(
+        to_ascii(&grid, Orientation::Pointy, |item| item.to_string()),
+        expected
+    );
+
+    assert_eq!(
+        to_ascii(&HexGrid::<()>::new(), Orientation::Flat, |_| String::new()),
+        ""
+    );
+}
+
+#[test]
+fn parse_ascii_test() {
+    let text = "
+        a b c
+         d e f
+        g h i
+    ";
+    let grid = parse_ascii(text, Some).unwrap();
+
+    assert_eq!(
+        grid,
+        HexGrid::from_offset_rows(
+            OffsetLayout::OddR,
+            vec![
+                vec!['a', 'b', 'c'],
+                vec!['d', 'e', 'f'],
+                vec!['g', 'h', 'i'],
+            ]
+        )
+    );
+    assert_eq!(grid.get(0, 1), Some(&'d'));
+    assert_eq!(grid.get(-1, 2), Some(&'g'));
+
+    let grid = parse_ascii("# .\n . #", |character| (character == '.').then_some(()));
+    assert_eq!(grid.unwrap().keys().collect::<Vec<_>>(), [&(0, 1), &(1, 0)]);
+
+    assert!(parse_ascii("\n  \n", Some).unwrap().is_empty());
+}
+
+#[test]
+fn parse_ascii_errors_test() {
+    assert_eq!(
+        parse_ascii("a b\na b", Some),
+        Err(ParseAsciiError::BadIndentation {
+            line: 2,
+            expected: 1,
+            found: 0
+        })
+    );
+    assert_eq!(
+        parse_ascii("a b\n ab", Some),
+        Err(ParseAsciiError::Misaligned { line: 2, column: 3 })
+    );
+    assert_eq!(
+        parse_ascii("a  b", Some),
+        Err(ParseAsciiError::Misaligned { line: 1, column: 3 })
+    );
+    assert_eq!(
+        parse_ascii("\na b c\n a b\n", Some),
+        Err(ParseAsciiError::Ragged {
+            line: 3,
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        parse_ascii("a b\n\na b", Some).unwrap_err().to_string(),
+        "line 2 is indented by 0 spaces, expected 1"
+    );
+}
+
+#[test]
+fn parse_and_render_test() {
+    let text = "1 2\n 3 4";
+    let grid = parse_ascii(text, |character| character.to_digit(10)).unwrap();
+
+    let rendered = to_ascii(&grid, Orientation::Pointy, |digit| digit.to_string());
+    assert!(rendered.contains("|  1  |  2  |"));
+    assert!(rendered.contains("   |  3  |  4  |"));
+}
@@ -1,124 +1,164 @@
-use crate::HexGrid;
-use crate::to_3d_coordinate;
-use std::collections::{BTreeMap, VecDeque};
+use crate::{Hex, HexGrid};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
-#[derive(Debug, Clone, PartialEq)]
+/// Entry in the open list, ordered so that the [`BinaryHeap`] pops the lowest `f` first.
+/// Ties are broken by preferring the larger `g` (the node closest to the goal),
+/// and then the smallest coordinate to keep the search deterministic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct AStarItem {
-    f: i32,
-    g: i32,
-    h: i32,
+    f: u32,
+    g: u32,
+    coord: Hex,
 }
 
 impl AStarItem {
-    fn new(g: i32, h: i32) -> Self {
-        AStarItem { g, h, f: g + h }
+    fn new(coord: Hex, g: u32, h: u32) -> Self {
+        AStarItem {
+            f: g.saturating_add(h),
+            g,
+            coord,
+        }
     }
 }
 
-fn find_best(
-    open_list: &BTreeMap<(i32, i32, i32), AStarItem>,
-) -> Option<(&(i32, i32, i32), &AStarItem)> {
-    // https://theknightsofu.com/pathfinding-on-a-hexagonal-grid-a-algorithm-2/
-    let mut best: Option<(&(i32, i32, i32), &AStarItem)> = None;
-    for (coord, item) in open_list.iter() {
-        if let Some((_, current_best)) = best {
-            match item.f.partial_cmp(&current_best.f) {
-                Some(core::cmp::Ordering::Less) => best = Some((coord, item)),
-                Some(core::cmp::Ordering::Equal) if item.g > current_best.g => {
-                    best = Some((coord, item))
-                }
-                _ => {}
-            }
-        } else {
-            best = Some((coord, item))
-        }
+impl Ord for AStarItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .f
+            .cmp(&self.f)
+            .then_with(|| self.g.cmp(&other.g))
+            .then_with(|| other.coord.cmp(&self.coord))
     }
-
-    best
 }
 
-fn closed_list_to_path(
-    closed_list: VecDeque<((i32, i32, i32), AStarItem)>,
-) -> Option<Vec<(i32, i32)>> {
-    let destination_index = closed_list.iter().rposition(|(_, item)| item.h == 0)?;
-    let mut reverse_index_path = vec![destination_index];
-    let mut current_index = destination_index;
-
-    loop {
-        let (prev_coord, prev) = &closed_list[current_index];
-        let next_item = closed_list
-            .iter()
-            .filter(|(_, x)| x.g == prev.g.saturating_sub(1))
-            .filter(|(a, _)| HexGrid::<()>::distance_3d(a, prev_coord) == 1)
-            .min_by_key(|(_, x)| x.h)
-            .expect("path is broken");
-        let next_index = closed_list
-            .iter()
-            .position(|x| x == next_item)
-            .expect("item is now not found?!");
-        reverse_index_path.push(next_index);
-        current_index = next_index;
-
-        if next_item.1.g == 0 {
-            // done
-            break;
-        }
+impl PartialOrd for AStarItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
+}
 
-    let mut path = Vec::new();
-
-    for pos in reverse_index_path.into_iter().rev() {
-        let (a, b, _) = closed_list[pos].0;
-        path.push((a, b))
+fn reconstruct_path(came_from: &HashMap<Hex, Hex>, destination: Hex) -> Vec<Hex> {
+    let mut path = vec![destination];
+    let mut current = destination;
+    while let Some(previous) = came_from.get(&current) {
+        path.push(*previous);
+        current = *previous;
     }
-
-    Some(path)
+    path.reverse();
+    path
 }
 
+/// calculates the shortest path between two points, every step costs 1.
 pub fn astar<T>(
     hexgrid: &HexGrid<T>,
-    point_a: (i32, i32),
-    point_b: (i32, i32),
+    point_a: impl Into<Hex>,
+    point_b: impl Into<Hex>,
 ) -> Option<Vec<(i32, i32)>> {
-    let mut open_list: BTreeMap<(i32, i32, i32), AStarItem> = BTreeMap::new();
-    let mut closed_list: VecDeque<((i32, i32, i32), AStarItem)> = VecDeque::new();
-    let point_a_3d = to_3d_coordinate(point_a.0, point_a.1);
-    let point_b_3d = to_3d_coordinate(point_b.0, point_b.1);
-
-    let h_start = HexGrid::<T>::distance_3d(&point_a_3d, &point_b_3d);
-    let start = AStarItem::new(0, h_start);
-    open_list.insert(point_a_3d, start);
-
-    let mut found = None;
-    while let Some((coord, best)) = find_best(&open_list) {
-        let best_copy = best.clone();
-        let coord_copy = coord.clone();
-        for around_coord in hexgrid.keys_around(1, (coord.0, coord.1)) {
-            let around_coord_3d = to_3d_coordinate(around_coord.0, around_coord.1);
-            if closed_list
-                .iter()
-                .find(|(c, _)| c == &around_coord_3d)
-                .is_some()
-            {
+    astar_with_min_cost(hexgrid, point_a, point_b, 1, |_, _, _| Some(1)).map(|(path, _)| path)
+}
+
+/// Like [`astar`], but only steps onto cells for which `passable` returns true.
+/// The starting cell itself is not checked.
+pub fn astar_where<T, F>(
+    hexgrid: &HexGrid<T>,
+    point_a: impl Into<Hex>,
+    point_b: impl Into<Hex>,
+    mut passable: F,
+) -> Option<Vec<(i32, i32)>>
+where
+    F: FnMut(&T) -> bool,
+{
+    astar_with_min_cost(hexgrid, point_a, point_b, 1, |_, _, item| {
+        passable(item).then_some(1)
+    })
+    .map(|(path, _)| path)
+}
+
+/// Like [`astar`], but every step costs whatever `cost` returns for entering the next cell.
+///
+/// `cost` is called with the coordinate that is stepped from, the coordinate that is stepped onto
+/// and the item on that cell. Returning `None` marks the cell as impassable.
+/// Any cost is allowed, free steps like roads or teleporters included, so the search goes as wide
+/// as Dijkstra would. Use [`astar_with_min_cost`] when every step costs at least some amount.
+///
+/// Returns the path together with its total cost.
+pub fn astar_with_cost<T, F>(
+    hexgrid: &HexGrid<T>,
+    point_a: impl Into<Hex>,
+    point_b: impl Into<Hex>,
+    cost: F,
+) -> Option<(Vec<(i32, i32)>, u32)>
+where
+    F: FnMut((i32, i32), (i32, i32), &T) -> Option<u32>,
+{
+    astar_with_min_cost(hexgrid, point_a, point_b, 0, cost)
+}
+
+/// Like [`astar_with_cost`], but the heuristic is scaled by `min_cost`, the cheapest any step can be.
+///
+/// The returned path is only guaranteed to be the cheapest one when `cost` never returns less than
+/// `min_cost`, the returned total is always the sum of the steps `cost` returned.
+pub fn astar_with_min_cost<T, F>(
+    hexgrid: &HexGrid<T>,
+    point_a: impl Into<Hex>,
+    point_b: impl Into<Hex>,
+    min_cost: u32,
+    mut cost: F,
+) -> Option<(Vec<(i32, i32)>, u32)>
+where
+    F: FnMut((i32, i32), (i32, i32), &T) -> Option<u32>,
+{
+    let mut open_list = BinaryHeap::new();
+    let mut closed_list = HashSet::new();
+    let mut came_from = HashMap::new();
+    let mut best_g = HashMap::new();
+
+    let point_a = point_a.into();
+    let point_b = point_b.into();
+    let heuristic = |point: &Hex| (point.distance(point_b) as u32).saturating_mul(min_cost);
+    open_list.push(AStarItem::new(point_a, 0, heuristic(&point_a)));
+    best_g.insert(point_a, 0);
+
+    while let Some(AStarItem { coord, g, .. }) = open_list.pop() {
+        if !closed_list.insert(coord) {
+            // stale entry, this coordinate was already reached in a cheaper way
+            continue;
+        }
+
+        if coord == point_b {
+            let path = reconstruct_path(&came_from, coord);
+            return Some((path.into_iter().map(Hex::into).collect(), g));
+        }
+
+        for (&around_coord, item) in hexgrid.iter_around(1, coord) {
+            let around_coord = Hex::from(around_coord);
+            if closed_list.contains(&around_coord) {
+                continue;
+            }
+            let Some(step) = cost(coord.into(), around_coord.into(), item) else {
                 continue;
             };
 
-            let distance = HexGrid::<T>::distance_3d(&around_coord_3d, &point_b_3d);
-            let item = AStarItem::new(best_copy.g + 1, distance);
-            if distance == 0 {
-                found = Some((around_coord_3d, item));
-                break;
+            let new_g = g.saturating_add(step);
+            if best_g
+                .get(&around_coord)
+                .is_some_and(|open_g| *open_g <= new_g)
+            {
+                continue;
             }
-            open_list.insert(around_coord_3d, item);
-        }
-        open_list.remove(&coord_copy);
-        closed_list.push_back((coord_copy, best_copy));
-        if let Some(asdfg) = found.take() {
-            closed_list.push_back(asdfg);
+
+            best_g.insert(around_coord, new_g);
+            came_from.insert(around_coord, coord);
+            open_list.push(AStarItem::new(
+                around_coord,
+                new_g,
+                heuristic(&around_coord),
+            ));
         }
     }
 
-    closed_list_to_path(closed_list)
+    None
 }
 
 #[cfg(test)]
@@ -151,3 +191,158 @@ fn simple_astar_not_found() {
 
     assert_eq!(None, path);
 }
+
+#[test]
+fn astar_around_wall() {
+    let grid = HexGrid::parse_ascii(
+        "
+        S . # . E
+         . . # . .
+        . . # . .
+         . . . . .
+        ",
+        |tile| (tile != '#').then_some(tile),
+    )
+    .unwrap();
+
+    let path = grid.astar((0, 0), (4, 0)).unwrap();
+
+    // the wall has to be passed below its last row, which takes 7 steps
+    assert_eq!(path.len(), 8);
+    assert_eq!(grid.get_by_point(path[0]), Some(&'S'));
+    assert_eq!(grid.get_by_point(path[7]), Some(&'E'));
+    assert!(
+        path.windows(2)
+            .all(|step| HexGrid::<char>::distance(step[0], step[1]) == 1)
+    );
+    assert!(path.iter().all(|point| grid.contains(point)));
+    assert!(path.iter().any(|point| point.1 == 3));
+}
+
+#[test]
+fn astar_with_cost_avoids_expensive_cells() {
+    //  A S B
+    //   F R
+    let grid = HexGrid::from(btree! {
+        (0, 0) => 'A',
+        (1, 0) => 'S',
+        (2, 0) => 'B',
+        (0, 1) => 'F',
+        (1, 1) => 'R',
+    });
+
+    let cost = |_, _, tile: &char| match tile {
+        'S' => Some(10),
+        _ => Some(1),
+    };
+
+    let result = grid.astar_with_cost((0, 0), (2, 0), cost);
+
+    assert_eq!(Some((vec![(0, 0), (0, 1), (1, 1), (2, 0)], 3)), result);
+}
+
+#[test]
+fn astar_with_zero_cost_corridor() {
+    //  S g g g E
+    //   r r r r
+    let grid = HexGrid::from(btree! {
+        (0, 0) => 'S',
+        (1, 0) => 'g',
+        (2, 0) => 'g',
+        (3, 0) => 'g',
+        (4, 0) => 'E',
+        (0, 1) => 'r',
+        (1, 1) => 'r',
+        (2, 1) => 'r',
+        (3, 1) => 'r',
+    });
+    let cost = |_, _, tile: &char| match tile {
+        'r' => Some(0),
+        _ => Some(3),
+    };
+    let road = vec![(0, 0), (0, 1), (1, 1), (2, 1), (3, 1), (4, 0)];
+
+    assert_eq!(
+        grid.astar_with_min_cost((0, 0), (4, 0), 0, cost),
+        Some((road.clone(), 3))
+    );
+    assert_eq!(grid.astar_with_cost((0, 0), (4, 0), cost), Some((road, 3)));
+}
+
+#[test]
+fn astar_with_min_cost_sums_the_steps() {
+    let mut grid = HexGrid::new();
+    for x in 0..5 {
+        grid.set(x, 0, ());
+    }
+    let row = vec![(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)];
+
+    assert_eq!(
+        grid.astar_with_min_cost((0, 0), (4, 0), 2, |_, _, _| Some(2)),
+        Some((row.clone(), 8))
+    );
+    assert_eq!(
+        grid.astar_with_min_cost((0, 0), (4, 0), 2, |_, _, _| Some(1)),
+        Some((row, 4))
+    );
+}
+
+#[test]
+fn astar_with_cost_impassable() {
+    let grid = HexGrid::from(btree! {
+        (0, 0) => true,
+        (1, 0) => false,
+        (2, 0) => true,
+    });
+
+    let result = grid.astar_with_cost((0, 0), (2, 0), |_, _, passable| passable.then_some(1));
+
+    assert_eq!(None, result);
+}
+
+#[test]
+fn astar_with_cost_same_point() {
+    let grid = HexGrid::from(btree! {
+        (0, 0) => true,
+    });
+
+    let result = grid.astar_with_cost((0, 0), (0, 0), |_, _, _| Some(1));
+
+    assert_eq!(Some((vec![(0, 0)], 0)), result);
+}
+
+#[test]
+fn astar_large_grid() {
+    let mut grid = HexGrid::new();
+    for x in 0..200 {
+        for y in 0..200 {
+            grid.set(x, y, ());
+        }
+    }
+
+    let path = grid.astar((0, 0), (199, 199)).expect("path exists");
+
+    assert_eq!(
+        path.len() as i32,
+        HexGrid::<()>::distance((0, 0), (199, 199)) + 1
+    );
+    assert_eq!(Some(&(0, 0)), path.first());
+    assert_eq!(Some(&(199, 199)), path.last());
+}
+
+#[test]
+fn astar_where_skips_impassable() {
+    //  . # .
+    //   . .
+    let grid = HexGrid::from(btree! {
+        (0, 0) => '.',
+        (1, 0) => '#',
+        (2, 0) => '.',
+        (0, 1) => '.',
+        (1, 1) => '.',
+    });
+
+    let path = grid.astar_where((0, 0), (2, 0), |tile| *tile != '#');
+
+    assert_eq!(Some(vec![(0, 0), (0, 1), (1, 1), (2, 0)]), path);
+}
@@ -0,0 +1,93 @@
+use crate::{Hex, HexGrid};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// finds every cell that can be reached from `start` while spending at most `budget`.
+///
+/// `cost` is called with the coordinate that is stepped from, the coordinate that is stepped onto
+/// and the item on that cell, returning `None` marks the cell as impassable.
+///
+/// Returns a grid with the cheapest cost to reach each cell, `start` itself is included with cost 0.
+pub fn reachable<T, F>(
+    hexgrid: &HexGrid<T>,
+    start: impl Into<Hex>,
+    budget: u32,
+    mut cost: F,
+) -> HexGrid<u32>
+where
+    F: FnMut((i32, i32), (i32, i32), &T) -> Option<u32>,
+{
+    let mut costs = HexGrid::new();
+    let mut open_list = BinaryHeap::new();
+    open_list.push(Reverse((0u32, start.into())));
+
+    while let Some(Reverse((spent, coord))) = open_list.pop() {
+        if costs.get_by_point(coord).is_some() {
+            // stale entry, this coordinate was already reached in a cheaper way
+            continue;
+        }
+        costs.insert(coord, spent);
+
+        for (around_coord, item) in hexgrid.iter_around(1, coord) {
+            if costs.get_by_point(around_coord).is_some() {
+                continue;
+            }
+            let Some(step) = cost(coord.into(), *around_coord, item) else {
+                continue;
+            };
+
+            let new_spent = spent.saturating_add(step);
+            if new_spent <= budget {
+                open_list.push(Reverse((new_spent, Hex::from(*around_coord))));
+            }
+        }
+    }
+
+    costs
+}
+
+#[cfg(test)]
+use collection_literals::btree;
+
+#[test]
+fn reachable_within_budget() {
+    //  A S B C
+    //   D E
+    let grid = HexGrid::from(btree! {
+        (0, 0) => 'A',
+        (1, 0) => 'S',
+        (2, 0) => 'B',
+        (3, 0) => 'C',
+        (0, 1) => 'D',
+        (1, 1) => 'E',
+    });
+
+    let cost = |_, _, tile: &char| match tile {
+        'S' => Some(5),
+        _ => Some(1),
+    };
+
+    let range = grid.reachable((0, 0), 3, cost);
+
+    let expected = HexGrid::from(btree! {
+        (0, 0) => 0,
+        (0, 1) => 1,
+        (1, 1) => 2,
+        (2, 0) => 3,
+    });
+
+    assert_eq!(expected, range);
+}
+
+#[test]
+fn reachable_impassable() {
+    let grid = HexGrid::from(btree! {
+        (0, 0) => true,
+        (1, 0) => false,
+        (2, 0) => true,
+    });
+
+    let range = grid.reachable((0, 0), 10, |_, _, passable| passable.then_some(1));
+
+    assert_eq!(HexGrid::from(btree! {(0, 0) => 0}), range);
+}
@@ -0,0 +1,282 @@
+use crate::Hex;
+
+/// The six directions from a hex to its neighbors, named for the pointy layout drawn on
+/// [`HexGrid`](crate::HexGrid).
+/// # Examples
+/// ```
+/// use hex_grid::{Direction, Hex};
+/// let direction = Direction::Right;
+///
+/// assert_eq!(direction.opposite(), Direction::Left);
+/// assert_eq!(direction.rotate_cw(2), Direction::DownLeft);
+/// assert_eq!(direction.rotate_ccw(1), Direction::UpRight);
+/// assert_eq!(Direction::between((1, 1), (2, 0)), Some(Direction::UpRight));
+/// assert_eq!(Direction::UpLeft.angle_degrees(), 120.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Right,
+    Left,
+    DownRight,
+    DownLeft,
+    UpRight,
+    UpLeft,
+}
+
+impl Direction {
+    /// all directions clockwise, starting with `Right`. [`Direction::index`] is the position in here.
+    pub const ALL: [Direction; 6] = [
+        Direction::Right,
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+        Direction::UpRight,
+    ];
+
+    /// the position in [`Direction::ALL`].
+    pub const fn index(self) -> usize {
+        match self {
+            Direction::Right => 0,
+            Direction::DownRight => 1,
+            Direction::DownLeft => 2,
+            Direction::Left => 3,
+            Direction::UpLeft => 4,
+            Direction::UpRight => 5,
+        }
+    }
+
+    /// the direction at `index` in [`Direction::ALL`], the index wraps around so `6` is `Right` again.
+    pub const fn from_index(index: usize) -> Self {
+        Direction::ALL[index % 6]
+    }
+
+    /// the point one step away in this direction, `None` when that would overflow.
+    pub fn apply_next(self, current_x: i32, current_y: i32) -> Option<(i32, i32)> {
+        let (dx, dy) = self.delta();
+        Some((current_x.checked_add(dx)?, current_y.checked_add(dy)?))
+    }
+
+    /// the step in axial coordinates that is taken when moving in this direction.
+    pub const fn delta(self) -> (i32, i32) {
+        match self {
+            Direction::Right => (1, 0),
+            Direction::Left => (-1, 0),
+            Direction::DownRight => (0, 1),
+            Direction::UpLeft => (0, -1),
+            Direction::DownLeft => (-1, 1),
+            Direction::UpRight => (1, -1),
+        }
+    }
+
+    pub const fn opposite(self) -> Self {
+        self.rotate_cw(3)
+    }
+
+    /// turns `steps` times by 60 degrees clockwise.
+    pub const fn rotate_cw(self, steps: usize) -> Self {
+        Direction::from_index(self.index() + steps % 6)
+    }
+
+    /// turns `steps` times by 60 degrees counter-clockwise.
+    pub const fn rotate_ccw(self, steps: usize) -> Self {
+        Direction::from_index(self.index() + 6 - steps % 6)
+    }
+
+    /// the direction of the step from `point_a` to `point_b`, `None` when they aren't neighbors.
+    pub fn between(point_a: impl Into<Hex>, point_b: impl Into<Hex>) -> Option<Self> {
+        let delta: (i32, i32) = (point_b.into() - point_a.into()).into();
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.delta() == delta)
+    }
+
+    /// the angle on the screen, counter-clockwise from `Right` like in math,
+    /// so `UpRight` is at 60 degrees and `DownRight` at 300.
+    pub const fn angle_degrees(self) -> f64 {
+        match self {
+            Direction::Right => 0.0,
+            Direction::UpRight => 60.0,
+            Direction::UpLeft => 120.0,
+            Direction::Left => 180.0,
+            Direction::DownLeft => 240.0,
+            Direction::DownRight => 300.0,
+        }
+    }
+}
+
+/// The six hexes at distance 2 that lie straight between two [`Direction`]s, past the corner
+/// where the two neighbors in those directions meet.
+/// # Examples
+/// ```
+/// use hex_grid::{Diagonal, Direction, Hex};
+/// let diagonal = Diagonal::UpRight;
+///
+/// assert_eq!(diagonal.delta(), (2, -1));
+/// assert_eq!(
+///     Hex::ORIGIN.neighbor(Direction::Right).neighbor(Direction::UpRight),
+///     Hex::ORIGIN.diagonal_neighbor(diagonal)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Diagonal {
+    UpRight,
+    DownRight,
+    Down,
+    DownLeft,
+    UpLeft,
+    Up,
+}
+
+impl Diagonal {
+    /// all diagonals clockwise, starting with `UpRight`.
+    pub const ALL: [Diagonal; 6] = [
+        Diagonal::UpRight,
+        Diagonal::DownRight,
+        Diagonal::Down,
+        Diagonal::DownLeft,
+        Diagonal::UpLeft,
+        Diagonal::Up,
+    ];
+
+    /// the point one step away in this diagonal, `None` when that would overflow.
+    pub fn apply_next(self, current_x: i32, current_y: i32) -> Option<(i32, i32)> {
+        let (dx, dy) = self.delta();
+        Some((current_x.checked_add(dx)?, current_y.checked_add(dy)?))
+    }
+
+    /// the step in axial coordinates that is taken when moving in this diagonal.
+    pub const fn delta(self) -> (i32, i32) {
+        match self {
+            Diagonal::UpRight => (2, -1),
+            Diagonal::DownRight => (1, 1),
+            Diagonal::Down => (-1, 2),
+            Diagonal::DownLeft => (-2, 1),
+            Diagonal::UpLeft => (-1, -1),
+            Diagonal::Up => (1, -2),
+        }
+    }
+
+    pub const fn opposite(self) -> Self {
+        match self {
+            Diagonal::UpRight => Diagonal::DownLeft,
+            Diagonal::DownRight => Diagonal::UpLeft,
+            Diagonal::Down => Diagonal::Up,
+            Diagonal::DownLeft => Diagonal::UpRight,
+            Diagonal::UpLeft => Diagonal::DownRight,
+            Diagonal::Up => Diagonal::Down,
+        }
+    }
+}
+
+/// The six directions named for a flat layout, where hexes have an edge at the top.
+///
+/// Every `FlatDirection` is one of the [`Direction`]s turned to fit the flat layout of
+/// [`Orientation::Flat`](crate::Orientation::Flat), so anything that takes a `impl Into<Direction>` takes a `FlatDirection` as well.
+/// # Examples
+/// ```
+/// use hex_grid::{Direction, FlatDirection, HexGrid};
+/// let mut grid = HexGrid::new();
+/// grid.set(0, 0, "A");
+/// grid.set(0, 1, "B");
+/// grid.set(1, 0, "C");
+///
+/// let data: Vec<_> = grid.values_direction(FlatDirection::Down, 0, 0).collect();
+/// assert_eq!(data, vec![&"A", &"B"]);
+/// let data: Vec<_> = grid.values_direction(FlatDirection::DownRight, 0, 0).collect();
+/// assert_eq!(data, vec![&"A", &"C"]);
+/// assert_eq!(Direction::from(FlatDirection::Down).delta(), (0, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FlatDirection {
+    Up,
+    Down,
+    UpRight,
+    DownRight,
+    UpLeft,
+    DownLeft,
+}
+
+impl From<FlatDirection> for Direction {
+    fn from(direction: FlatDirection) -> Self {
+        match direction {
+            FlatDirection::Up => Direction::UpLeft,
+            FlatDirection::Down => Direction::DownRight,
+            FlatDirection::UpRight => Direction::UpRight,
+            FlatDirection::DownRight => Direction::Right,
+            FlatDirection::UpLeft => Direction::Left,
+            FlatDirection::DownLeft => Direction::DownLeft,
+        }
+    }
+}
+
+impl From<Direction> for FlatDirection {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::UpLeft => FlatDirection::Up,
+            Direction::DownRight => FlatDirection::Down,
+            Direction::UpRight => FlatDirection::UpRight,
+            Direction::Right => FlatDirection::DownRight,
+            Direction::Left => FlatDirection::UpLeft,
+            Direction::DownLeft => FlatDirection::DownLeft,
+        }
+    }
+}
+
+#[test]
+fn direction_test() {
+    for (index, direction) in Direction::ALL.into_iter().enumerate() {
+        assert_eq!(direction.index(), index);
+        assert_eq!(Direction::from_index(index), direction);
+        assert_eq!(Direction::from_index(index + 6), direction);
+
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_eq!(
+            direction.opposite().delta(),
+            (-direction.delta().0, -direction.delta().1)
+        );
+        assert_eq!(direction.rotate_cw(1), Direction::from_index(index + 1));
+        assert_eq!(direction.rotate_cw(4).rotate_ccw(4), direction);
+        assert_eq!(direction.rotate_ccw(7), direction.rotate_cw(5));
+        assert_eq!(direction.rotate_cw(6), direction);
+
+        let neighbor = Hex::new(2, -1).neighbor(direction);
+        assert_eq!(Direction::between((2, -1), neighbor), Some(direction));
+
+        // clockwise on the screen is a smaller angle
+        assert_eq!(
+            (direction.angle_degrees() - direction.rotate_cw(1).angle_degrees() + 360.0) % 360.0,
+            60.0
+        );
+    }
+
+    assert_eq!(Direction::between((0, 0), (0, 0)), None);
+    assert_eq!(Direction::between((0, 0), (2, 0)), None);
+    assert_eq!(Direction::between((0, 0), (1, 1)), None);
+    assert_eq!(Direction::Right.apply_next(i32::MAX, 0), None);
+    assert_eq!(Direction::DownLeft.apply_next(3, 4), Some((2, 5)));
+}
+
+#[test]
+fn diagonal_test() {
+    for (index, diagonal) in Diagonal::ALL.into_iter().enumerate() {
+        let hex = Hex::ORIGIN.diagonal_neighbor(diagonal);
+        assert_eq!(hex.length(), 2);
+        assert_eq!(Hex::ORIGIN.diagonal_neighbor(diagonal.opposite()), -hex);
+
+        // a diagonal lies between two neighboring directions
+        let direction = Direction::ALL[(index + 5) % 6];
+        assert_eq!(
+            Hex::ORIGIN
+                .neighbor(direction)
+                .neighbor(direction.rotate_cw(1)),
+            hex
+        );
+    }
+
+    assert_eq!(Diagonal::Up.apply_next(0, i32::MIN + 1), None);
+    assert_eq!(Diagonal::Down.apply_next(1, 1), Some((0, 3)));
+}
@@ -1,4 +1,4 @@
-use crate::{Direction, HexGrid};
+use crate::{Diagonal, Direction, HexGrid};
 
 pub struct Values<'a, T> {
     pub(crate) iter: DirectionIterator<'a, T>,
@@ -36,19 +36,39 @@ impl<'a, T> Iterator for ValuesMut<'a, T> {
     }
 }
 
+/// Iterates from a start point in a straight line, one step in a [`Direction`] or [`Diagonal`]
+/// at a time, until there is no item at the next point.
 pub struct DirectionIterator<'a, T> {
     grid: &'a HexGrid<T>,
-    direction: Direction,
+    delta: (i32, i32),
     current_x: i32,
     current_y: i32,
     done: bool,
 }
 
 impl<'a, T> DirectionIterator<'a, T> {
-    pub fn new(grid: &'a HexGrid<T>, direction: Direction, current_x: i32, current_y: i32) -> Self {
+    pub fn new(
+        grid: &'a HexGrid<T>,
+        direction: impl Into<Direction>,
+        current_x: i32,
+        current_y: i32,
+    ) -> Self {
+        Self::with_delta(grid, direction.into().delta(), current_x, current_y)
+    }
+
+    pub fn diagonal(
+        grid: &'a HexGrid<T>,
+        diagonal: Diagonal,
+        current_x: i32,
+        current_y: i32,
+    ) -> Self {
+        Self::with_delta(grid, diagonal.delta(), current_x, current_y)
+    }
+
+    fn with_delta(grid: &'a HexGrid<T>, delta: (i32, i32), current_x: i32, current_y: i32) -> Self {
         DirectionIterator {
             grid,
-            direction,
+            delta,
             current_x,
             current_y,
             done: false,
@@ -65,40 +85,59 @@ impl<'a, T> Iterator for DirectionIterator<'a, T> {
         }
         let item = self.grid.get_key_value(self.current_x, self.current_y)?;
 
-        if let Some((current_x, current_y)) =
-            self.direction.apply_next(self.current_x, self.current_y)
-        {
-            self.current_x = current_x;
-            self.current_y = current_y;
-        } else {
-            self.done = true
+        match (
+            self.current_x.checked_add(self.delta.0),
+            self.current_y.checked_add(self.delta.1),
+        ) {
+            (Some(current_x), Some(current_y)) => {
+                self.current_x = current_x;
+                self.current_y = current_y;
+            }
+            _ => self.done = true,
         }
 
         Some(item)
     }
 }
 
+/// Mutable version of [`DirectionIterator`]. It collects its items up front with
+/// [`HexGrid::get_many_mut`], which walks every cell in the columns between the start and the end,
+/// so the cost grows with the width of the grid and not only with the length of the ray.
 pub struct DirectionIteratorMut<'a, T> {
-    grid: &'a mut HexGrid<T>,
-    direction: Direction,
-    current_x: i32,
-    current_y: i32,
-    done: bool,
+    iter: std::vec::IntoIter<((i32, i32), &'a mut T)>,
 }
 
 impl<'a, T> DirectionIteratorMut<'a, T> {
     pub fn new(
         grid: &'a mut HexGrid<T>,
-        direction: Direction,
+        direction: impl Into<Direction>,
+        current_x: i32,
+        current_y: i32,
+    ) -> Self {
+        Self::with_delta(grid, direction.into().delta(), current_x, current_y)
+    }
+
+    pub fn diagonal(
+        grid: &'a mut HexGrid<T>,
+        diagonal: Diagonal,
         current_x: i32,
         current_y: i32,
     ) -> Self {
+        Self::with_delta(grid, diagonal.delta(), current_x, current_y)
+    }
+
+    fn with_delta(
+        grid: &'a mut HexGrid<T>,
+        delta: (i32, i32),
+        current_x: i32,
+        current_y: i32,
+    ) -> Self {
+        let keys: Vec<_> = DirectionIterator::with_delta(grid, delta, current_x, current_y)
+            .map(|(key, _)| *key)
+            .collect();
+
         DirectionIteratorMut {
-            grid,
-            direction,
-            current_x,
-            current_y,
-            done: false,
+            iter: grid.get_many_mut(keys).into_iter(),
         }
     }
 }
@@ -107,32 +146,11 @@ impl<'a, T> Iterator for DirectionIteratorMut<'a, T> {
     type Item = ((i32, i32), &'a mut T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.done {
-            return None;
-        }
-
-        // ps: this is from chatgpt
-        let grid_ptr = self.grid as *mut HexGrid<T>;
-        let key = (self.current_x, self.current_y);
-
-        // SAFETY: We're the exclusive owner of `self.grid`, and we yield only one &mut at a time
-        let item = unsafe { (*grid_ptr).data.get_mut(&key) }?;
-        // end ps
-
-        if let Some((current_x, current_y)) =
-            self.direction.apply_next(self.current_x, self.current_y)
-        {
-            self.current_x = current_x;
-            self.current_y = current_y;
-        } else {
-            self.done = true
-        }
-
-        Some((key, item))
+        self.iter.next()
     }
 }
 
-impl<'a, T> IntoIterator for HexGrid<T> {
+impl<T> IntoIterator for HexGrid<T> {
     type Item = ((i32, i32), T);
 
     type IntoIter = std::collections::btree_map::IntoIter<(i32, i32), T>;
@@ -142,6 +160,26 @@ impl<'a, T> IntoIterator for HexGrid<T> {
     }
 }
 
+impl<'a, T> IntoIterator for &'a HexGrid<T> {
+    type Item = (&'a (i32, i32), &'a T);
+
+    type IntoIter = std::collections::btree_map::Iter<'a, (i32, i32), T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut HexGrid<T> {
+    type Item = (&'a (i32, i32), &'a mut T);
+
+    type IntoIter = std::collections::btree_map::IterMut<'a, (i32, i32), T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
 #[cfg(test)]
 use collection_literals::btree;
 
@@ -197,3 +235,64 @@ fn iter_mut_test() {
     assert_eq!(expected, grid);
     assert_eq!(vec![(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)], keys);
 }
+
+#[test]
+fn flat_direction_test() {
+    use crate::{FlatDirection, Hex, Layout, Orientation};
+
+    let layout = Layout::new(Orientation::Flat, (1.0, 1.0), (0.0, 0.0));
+    let moves = [
+        (FlatDirection::Up, (0.0, -1.0)),
+        (FlatDirection::Down, (0.0, 1.0)),
+        (FlatDirection::UpRight, (1.0, -1.0)),
+        (FlatDirection::DownRight, (1.0, 1.0)),
+        (FlatDirection::UpLeft, (-1.0, -1.0)),
+        (FlatDirection::DownLeft, (-1.0, 1.0)),
+    ];
+
+    for (direction, (x_sign, y_sign)) in moves {
+        // the step lands on the screen in the direction the name says
+        let (x, y) = layout.hex_to_pixel(Hex::ORIGIN.neighbor(direction));
+        assert!(x.signum() == x_sign || x.abs() < 1e-9, "{direction:?}");
+        assert_eq!(y.signum(), y_sign, "{direction:?}");
+
+        assert_eq!(FlatDirection::from(Direction::from(direction)), direction);
+    }
+
+    let mut grid = HexGrid::new();
+    grid.set(0, 0, 1);
+    grid.set(0, 1, 2);
+    grid.set(0, 2, 3);
+    grid.set(1, 1, 4);
+
+    let out: Vec<_> = grid.values_direction(FlatDirection::Down, 0, 0).collect();
+    assert_eq!(out, vec![&1, &2, &3]);
+    let out: Vec<_> = grid.keys_direction(FlatDirection::Up, 0, 2).collect();
+    assert_eq!(out, vec![&(0, 2), &(0, 1), &(0, 0)]);
+    let out: Vec<_> = grid.values_direction(FlatDirection::UpLeft, 1, 1).collect();
+    assert_eq!(out, vec![&4, &2]);
+}
+
+#[test]
+fn diagonal_iterator_test() {
+    let mut grid = HexGrid::new();
+    grid.set(0, 0, 1);
+    grid.set(1, 0, 2);
+    grid.set(2, -1, 3);
+    grid.set(4, -2, 4);
+    grid.set(-1, 2, 5);
+
+    let out: Vec<_> = grid.values_diagonal(Diagonal::UpRight, 0, 0).collect();
+    assert_eq!(out, vec![&1, &3, &4]);
+    let out: Vec<_> = grid.keys_diagonal(Diagonal::DownLeft, 4, -2).collect();
+    assert_eq!(out, vec![&(4, -2), &(2, -1), &(0, 0)]);
+    let out: Vec<_> = grid.values_diagonal(Diagonal::Down, 0, 0).collect();
+    assert_eq!(out, vec![&1, &5]);
+    assert_eq!(grid.values_diagonal(Diagonal::Up, 1, 0).count(), 1);
+
+    for value in grid.values_diagonal_mut(Diagonal::UpRight, 0, 0) {
+        *value *= 10;
+    }
+    let out: Vec<_> = grid.iter_diagonal(Diagonal::UpRight, 0, 0).collect();
+    assert_eq!(out, vec![(&(0, 0), &10), (&(2, -1), &30), (&(4, -2), &40),]);
+}
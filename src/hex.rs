@@ -0,0 +1,417 @@
+use crate::{
+    Axis, Diagonal, Direction, OffsetCoord, OffsetLayout, abs_max_3d_point, to_3d_coordinate,
+};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on the grid in axial coordinates, see the layout drawn on [`HexGrid`](crate::HexGrid).
+///
+/// `q` grows to the right and `r` grows down to the right, tuples `(q, r)` convert into a `Hex`
+/// so every method on the grid that takes a `impl Into<Hex>` also accepts a tuple.
+/// # Examples
+/// ```
+/// use hex_grid::{Direction, Hex};
+/// let a = Hex::new(1, 2);
+/// let b = Hex::from((3, 1));
+///
+/// assert_eq!(a + b, Hex::new(4, 3));
+/// assert_eq!(b - a, Hex::new(2, -1));
+/// assert_eq!(a * 2, Hex::new(2, 4));
+/// assert_eq!(a.distance(b), 2);
+/// assert_eq!(a.neighbor(Direction::Right), Hex::new(2, 2));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex::new(0, 0);
+
+    pub const fn new(q: i32, r: i32) -> Self {
+        Hex { q, r }
+    }
+
+    /// the third coordinate, it always holds that `q + r + s == 0`.
+    pub const fn s(self) -> i32 {
+        -self.q - self.r
+    }
+
+    pub const fn to_cube(self) -> Cube {
+        Cube {
+            q: self.q,
+            r: self.r,
+            s: self.s(),
+        }
+    }
+
+    /// the column and row of this hex in the given offset layout.
+    pub fn to_offset(self, layout: OffsetLayout) -> OffsetCoord {
+        OffsetCoord::from_hex(self, layout)
+    }
+
+    /// the distance to the origin.
+    pub fn length(self) -> i32 {
+        abs_max_3d_point((self.q, self.r, self.s()))
+    }
+
+    /// the number of steps between two hexes, this doesn't check if it is possible in a grid.
+    pub fn distance(self, other: impl Into<Hex>) -> i32 {
+        (self - other.into()).length()
+    }
+
+    /// the hex next to this one in the given direction.
+    pub fn neighbor(self, direction: impl Into<Direction>) -> Hex {
+        self + Hex::from(direction.into().delta())
+    }
+
+    /// turns this hex around `center` by `steps` times 60 degrees, clockwise for positive steps
+    /// and counter-clockwise for negative steps.
+    /// # Examples
+    /// ```
+    /// use hex_grid::Hex;
+    /// let hex = Hex::new(2, 0);
+    ///
+    /// assert_eq!(hex.rotate_around((0, 0), 1), Hex::new(0, 2));
+    /// assert_eq!(hex.rotate_around((0, 0), -1), Hex::new(2, -2));
+    /// assert_eq!(hex.rotate_around((1, 0), 3), Hex::new(0, 0));
+    /// ```
+    pub fn rotate_around(self, center: impl Into<Hex>, steps: i32) -> Hex {
+        let center = center.into();
+        let (mut q, mut r, mut s) = to_3d_coordinate((self - center).q, (self - center).r);
+        for _ in 0..steps.rem_euclid(6) {
+            (q, r, s) = (-r, -s, -q);
+        }
+        debug_assert_eq!(q + r + s, 0);
+        center + Hex::new(q, r)
+    }
+
+    /// mirrors this hex across one of the axes through the origin, see [`Axis`].
+    pub fn reflect(self, axis: Axis) -> Hex {
+        let (q, r, s) = to_3d_coordinate(self.q, self.r);
+        match axis {
+            Axis::Q => Hex::new(q, s),
+            Axis::R => Hex::new(s, r),
+            Axis::S => Hex::new(r, q),
+        }
+    }
+
+    /// the hex at distance 2 in the given diagonal.
+    pub fn diagonal_neighbor(self, diagonal: Diagonal) -> Hex {
+        self + Hex::from(diagonal.delta())
+    }
+
+    /// all six diagonal neighbors, clockwise starting with [`Diagonal::UpRight`].
+    pub fn diagonal_neighbors(self) -> [Hex; 6] {
+        Diagonal::ALL.map(|diagonal| self.diagonal_neighbor(diagonal))
+    }
+
+    /// all six hexes next to this one, clockwise starting with [`Direction::Right`].
+    pub fn neighbors(self) -> [Hex; 6] {
+        Direction::ALL.map(|direction| self.neighbor(direction))
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.q, self.r)
+    }
+}
+
+impl From<(i32, i32)> for Hex {
+    fn from((q, r): (i32, i32)) -> Self {
+        Hex { q, r }
+    }
+}
+
+impl From<&(i32, i32)> for Hex {
+    fn from(point: &(i32, i32)) -> Self {
+        Hex::from(*point)
+    }
+}
+
+impl From<&Hex> for Hex {
+    fn from(hex: &Hex) -> Self {
+        *hex
+    }
+}
+
+impl From<Hex> for (i32, i32) {
+    fn from(hex: Hex) -> Self {
+        (hex.q, hex.r)
+    }
+}
+
+impl From<Cube> for Hex {
+    fn from(cube: Cube) -> Self {
+        Hex::new(cube.q, cube.r)
+    }
+}
+
+impl PartialEq<(i32, i32)> for Hex {
+    fn eq(&self, other: &(i32, i32)) -> bool {
+        (self.q, self.r) == *other
+    }
+}
+
+impl PartialEq<Hex> for (i32, i32) {
+    fn eq(&self, other: &Hex) -> bool {
+        other == self
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: Hex) -> Hex {
+        Hex::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Hex) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: Hex) -> Hex {
+        Hex::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, rhs: Hex) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+
+    fn mul(self, rhs: i32) -> Hex {
+        Hex::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Hex {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+/// A position in cube coordinates, the same as the 3d coordinate of [`to_3d_coordinate`].
+///
+/// The coordinates always add up to 0, so a `Cube` can only be made from a [`Hex`] or
+/// through [`Cube::new`] which checks this.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "crate::serde_impl::CubeRepr",
+        into = "crate::serde_impl::CubeRepr"
+    )
+)]
+pub struct Cube {
+    q: i32,
+    r: i32,
+    s: i32,
+}
+
+impl Cube {
+    pub fn new(q: i32, r: i32, s: i32) -> Result<Self, InvalidCube> {
+        if q as i64 + r as i64 + s as i64 != 0 {
+            return Err(InvalidCube { q, r, s });
+        }
+
+        Ok(Cube { q, r, s })
+    }
+
+    pub const fn q(self) -> i32 {
+        self.q
+    }
+
+    pub const fn r(self) -> i32 {
+        self.r
+    }
+
+    pub const fn s(self) -> i32 {
+        self.s
+    }
+
+    pub const fn to_hex(self) -> Hex {
+        Hex::new(self.q, self.r)
+    }
+
+    /// the number of steps between two positions.
+    pub fn distance(self, other: Cube) -> i32 {
+        abs_max_3d_point((self.q - other.q, self.r - other.r, self.s - other.s))
+    }
+}
+
+impl From<Hex> for Cube {
+    fn from(hex: Hex) -> Self {
+        hex.to_cube()
+    }
+}
+
+impl From<Cube> for (i32, i32, i32) {
+    fn from(cube: Cube) -> Self {
+        (cube.q, cube.r, cube.s)
+    }
+}
+
+impl TryFrom<(i32, i32, i32)> for Cube {
+    type Error = InvalidCube;
+
+    fn try_from((q, r, s): (i32, i32, i32)) -> Result<Self, Self::Error> {
+        Cube::new(q, r, s)
+    }
+}
+
+impl Add for Cube {
+    type Output = Cube;
+
+    fn add(self, rhs: Cube) -> Cube {
+        (self.to_hex() + rhs.to_hex()).to_cube()
+    }
+}
+
+impl Sub for Cube {
+    type Output = Cube;
+
+    fn sub(self, rhs: Cube) -> Cube {
+        (self.to_hex() - rhs.to_hex()).to_cube()
+    }
+}
+
+impl Mul<i32> for Cube {
+    type Output = Cube;
+
+    fn mul(self, rhs: i32) -> Cube {
+        (self.to_hex() * rhs).to_cube()
+    }
+}
+
+impl Neg for Cube {
+    type Output = Cube;
+
+    fn neg(self) -> Cube {
+        (-self.to_hex()).to_cube()
+    }
+}
+
+/// returned when the three coordinates of a cube don't add up to 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCube {
+    pub q: i32,
+    pub r: i32,
+    pub s: i32,
+}
+
+impl fmt::Display for InvalidCube {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid cube coordinate ({}, {}, {}), the coordinates must add up to 0",
+            self.q, self.r, self.s
+        )
+    }
+}
+
+impl std::error::Error for InvalidCube {}
+
+/// returned when a doubled coordinate doesn't point to a hex, because `column + row` is odd.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDoubled {
+    pub column: i32,
+    pub row: i32,
+}
+
+impl fmt::Display for InvalidDoubled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid doubled coordinate ({}, {}), the column and row must add up to an even number",
+            self.column, self.row
+        )
+    }
+}
+
+impl std::error::Error for InvalidDoubled {}
+
+#[test]
+fn hex_arithmetic_test() {
+    let a = Hex::new(1, -2);
+    let b = Hex::new(-3, 5);
+
+    assert_eq!(a + b, Hex::new(-2, 3));
+    assert_eq!(a - b, Hex::new(4, -7));
+    assert_eq!(a * 3, Hex::new(3, -6));
+    assert_eq!(-a, Hex::new(-1, 2));
+    assert_eq!(a.s(), 1);
+    assert_eq!(a.length(), 2);
+    assert_eq!(a.distance(b), 7);
+    assert_eq!(a.distance((1, -2)), 0);
+    assert_eq!(<(i32, i32)>::from(a), (1, -2));
+    assert_eq!(Hex::from(&(1, -2)), a);
+    assert_eq!(a, (1, -2));
+}
+
+#[test]
+fn hex_neighbors_test() {
+    let center = Hex::new(2, 1);
+    assert_eq!(
+        center.neighbors(),
+        [(3, 1), (2, 2), (1, 2), (1, 1), (2, 0), (3, 0)].map(Hex::from)
+    );
+    assert!(
+        center
+            .neighbors()
+            .iter()
+            .all(|neighbor| center.distance(neighbor) == 1)
+    );
+}
+
+#[test]
+fn cube_test() {
+    let cube = Hex::new(2, 1).to_cube();
+    assert_eq!(<(i32, i32, i32)>::from(cube), (2, 1, -3));
+    assert_eq!(Cube::new(2, 1, -3), Ok(cube));
+    assert_eq!(Cube::try_from((2, 1, -3)), Ok(cube));
+    assert_eq!(Cube::new(2, 1, 0), Err(InvalidCube { q: 2, r: 1, s: 0 }));
+    assert_eq!(Hex::from(cube), Hex::new(2, 1));
+    assert_eq!(cube.distance(Cube::default()), 3);
+    assert_eq!(-cube + cube * 2, cube);
+}
+
+#[test]
+fn rotate_and_reflect_test() {
+    let hex = Hex::new(3, -1);
+    let center = Hex::new(-1, 2);
+
+    for steps in -7..=7 {
+        let rotated = hex.rotate_around(center, steps);
+        assert_eq!(rotated.distance(center), hex.distance(center));
+        assert_eq!(rotated, hex.rotate_around(center, steps + 6));
+        assert_eq!(rotated.rotate_around(center, -steps), hex);
+    }
+    assert_eq!(Hex::new(1, 0).rotate_around((0, 0), 1), Hex::new(0, 1));
+    assert_eq!(Hex::new(1, 0).rotate_around((0, 0), -1), Hex::new(1, -1));
+    assert_eq!(center.rotate_around(center, 2), center);
+
+    assert_eq!(hex.reflect(Axis::Q), Hex::new(3, -2));
+    assert_eq!(hex.reflect(Axis::R), Hex::new(-2, -1));
+    assert_eq!(hex.reflect(Axis::S), Hex::new(-1, 3));
+    for axis in [Axis::Q, Axis::R, Axis::S] {
+        assert_eq!(hex.reflect(axis).reflect(axis), hex);
+        assert_eq!(hex.reflect(axis).length(), hex.length());
+    }
+}
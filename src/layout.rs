@@ -0,0 +1,234 @@
+use crate::Hex;
+use std::f64::consts::PI;
+
+/// Which way the hexes point, the diagrams on [`HexGrid`](crate::HexGrid) are drawn pointy.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// a corner at the top, rows of hexes run from left to right.
+    #[default]
+    Pointy,
+    /// an edge at the top, columns of hexes run from top to bottom.
+    Flat,
+}
+
+/// the forward and inverse matrix between axial and pixel coordinates, and the angle of the
+/// first corner in sixths of a full turn.
+struct Matrix {
+    forward: [f64; 4],
+    inverse: [f64; 4],
+    start_angle: f64,
+}
+
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+impl Orientation {
+    fn matrix(self) -> Matrix {
+        match self {
+            Orientation::Pointy => Matrix {
+                forward: [SQRT_3, SQRT_3 / 2.0, 0.0, 3.0 / 2.0],
+                inverse: [SQRT_3 / 3.0, -1.0 / 3.0, 0.0, 2.0 / 3.0],
+                start_angle: 0.5,
+            },
+            Orientation::Flat => Matrix {
+                forward: [3.0 / 2.0, 0.0, SQRT_3 / 2.0, SQRT_3],
+                inverse: [2.0 / 3.0, 0.0, -1.0 / 3.0, SQRT_3 / 3.0],
+                start_angle: 0.0,
+            },
+        }
+    }
+}
+
+/// Converts between hexes and pixels, for drawing the grid and for finding the hex under the mouse.
+///
+/// `size` is the distance from the center of a hex to its corners, separately for x and y so hexes
+/// can be stretched, and `origin` is the pixel where the center of hex `(0, 0)` ends up.
+/// Pixel coordinates grow to the right and down, like on a screen.
+/// # Examples
+/// ```
+/// use hex_grid::{Hex, Layout, Orientation};
+/// let layout = Layout::new(Orientation::Pointy, (10.0, 10.0), (100.0, 100.0));
+///
+/// let (x, y) = layout.hex_to_pixel((1, 0));
+/// assert!((x - 117.32).abs() < 0.01);
+/// assert!((y - 100.0).abs() < 0.01);
+///
+/// assert_eq!(layout.pixel_to_hex((118.0, 103.0)).round(), Hex::new(1, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Layout {
+    pub orientation: Orientation,
+    pub size: (f64, f64),
+    pub origin: (f64, f64),
+}
+
+impl Layout {
+    pub fn new(orientation: Orientation, size: (f64, f64), origin: (f64, f64)) -> Self {
+        Layout {
+            orientation,
+            size,
+            origin,
+        }
+    }
+
+    /// the pixel at the center of a hex.
+    pub fn hex_to_pixel(&self, hex: impl Into<Hex>) -> (f64, f64) {
+        let hex = hex.into();
+        let matrix = self.orientation.matrix().forward;
+        let (q, r) = (hex.q as f64, hex.r as f64);
+
+        let x = (matrix[0] * q + matrix[1] * r) * self.size.0;
+        let y = (matrix[2] * q + matrix[3] * r) * self.size.1;
+
+        (x + self.origin.0, y + self.origin.1)
+    }
+
+    /// the fractional hex at a pixel, use [`FractionalHex::round`] to get the hex that contains it.
+    pub fn pixel_to_hex(&self, pixel: (f64, f64)) -> FractionalHex {
+        let matrix = self.orientation.matrix().inverse;
+        let x = (pixel.0 - self.origin.0) / self.size.0;
+        let y = (pixel.1 - self.origin.1) / self.size.1;
+
+        FractionalHex::new(matrix[0] * x + matrix[1] * y, matrix[2] * x + matrix[3] * y)
+    }
+
+    /// the six corners of a hex, going clockwise on the screen.
+    pub fn hex_corners(&self, hex: impl Into<Hex>) -> [(f64, f64); 6] {
+        let center = self.hex_to_pixel(hex);
+        let start_angle = self.orientation.matrix().start_angle;
+
+        std::array::from_fn(|corner| {
+            let angle = 2.0 * PI * (start_angle + corner as f64) / 6.0;
+            (
+                center.0 + self.size.0 * angle.cos(),
+                center.1 + self.size.1 * angle.sin(),
+            )
+        })
+    }
+}
+
+/// A position somewhere inside a hex, as returned by [`Layout::pixel_to_hex`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FractionalHex {
+    pub q: f64,
+    pub r: f64,
+}
+
+impl FractionalHex {
+    pub const fn new(q: f64, r: f64) -> Self {
+        FractionalHex { q, r }
+    }
+
+    /// the third coordinate, it always holds that `q + r + s == 0`.
+    pub fn s(self) -> f64 {
+        -self.q - self.r
+    }
+
+    /// the point at `t` of the way from `self` to `other`.
+    pub fn lerp(self, other: FractionalHex, t: f64) -> FractionalHex {
+        FractionalHex::new(
+            self.q + (other.q - self.q) * t,
+            self.r + (other.r - self.r) * t,
+        )
+    }
+
+    /// the hex that contains this position.
+    ///
+    /// Rounding every coordinate on its own can end up at coordinates that don't add up to 0,
+    /// so the coordinate that was rounded the most is recalculated from the other two.
+    pub fn round(self) -> Hex {
+        let (q, r, s) = (self.q, self.r, self.s());
+        let mut rounded_q = q.round();
+        let mut rounded_r = r.round();
+        let rounded_s = s.round();
+
+        let q_diff = (rounded_q - q).abs();
+        let r_diff = (rounded_r - r).abs();
+        let s_diff = (rounded_s - s).abs();
+
+        if q_diff > r_diff && q_diff > s_diff {
+            rounded_q = -rounded_r - rounded_s;
+        } else if r_diff > s_diff {
+            rounded_r = -rounded_q - rounded_s;
+        }
+
+        Hex::new(rounded_q as i32, rounded_r as i32)
+    }
+}
+
+impl From<Hex> for FractionalHex {
+    fn from(hex: Hex) -> Self {
+        FractionalHex::new(hex.q as f64, hex.r as f64)
+    }
+}
+
+#[cfg(test)]
+fn assert_close(a: (f64, f64), b: (f64, f64)) {
+    assert!(
+        (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9,
+        "{a:?} != {b:?}"
+    );
+}
+
+#[test]
+fn hex_to_pixel_test() {
+    let pointy = Layout::new(Orientation::Pointy, (1.0, 1.0), (0.0, 0.0));
+    assert_close(pointy.hex_to_pixel((0, 0)), (0.0, 0.0));
+    assert_close(pointy.hex_to_pixel((1, 0)), (SQRT_3, 0.0));
+    assert_close(pointy.hex_to_pixel((0, 1)), (SQRT_3 / 2.0, 1.5));
+    assert_close(pointy.hex_to_pixel((1, -2)), (0.0, -3.0));
+
+    let flat = Layout::new(Orientation::Flat, (2.0, 1.0), (5.0, 5.0));
+    assert_close(flat.hex_to_pixel((1, 0)), (8.0, 5.0 + SQRT_3 / 2.0));
+    assert_close(flat.hex_to_pixel((0, 1)), (5.0, 5.0 + SQRT_3));
+}
+
+#[test]
+fn pixel_to_hex_round_trip_test() {
+    for orientation in [Orientation::Pointy, Orientation::Flat] {
+        let layout = Layout::new(orientation, (12.0, 9.0), (-30.0, 40.0));
+        for hex in crate::range_iterator::RangeCoordinates::new(4, (1, -1)) {
+            let hex = crate::Hex::from(hex);
+            let pixel = layout.hex_to_pixel(hex);
+            let fractional = layout.pixel_to_hex(pixel);
+            assert!((fractional.q - hex.q as f64).abs() < 1e-9);
+            assert!((fractional.r - hex.r as f64).abs() < 1e-9);
+
+            // every corner is shared with neighbors, so stay a little inside the hex
+            for corner in layout.hex_corners(hex) {
+                let inside = (
+                    pixel.0 + (corner.0 - pixel.0) * 0.9,
+                    pixel.1 + (corner.1 - pixel.1) * 0.9,
+                );
+                assert_eq!(layout.pixel_to_hex(inside).round(), hex);
+            }
+        }
+    }
+}
+
+#[test]
+fn hex_corners_test() {
+    let pointy = Layout::new(Orientation::Pointy, (1.0, 1.0), (0.0, 0.0));
+    let corners = pointy.hex_corners((0, 0));
+    assert_close(corners[0], (SQRT_3 / 2.0, 0.5));
+    assert_close(corners[1], (0.0, 1.0));
+    assert_close(corners[4], (0.0, -1.0));
+
+    let flat = Layout::new(Orientation::Flat, (1.0, 1.0), (0.0, 0.0));
+    let corners = flat.hex_corners((0, 0));
+    assert_close(corners[0], (1.0, 0.0));
+    assert_close(corners[3], (-1.0, 0.0));
+}
+
+#[test]
+fn round_test() {
+    assert_eq!(FractionalHex::new(0.4, 0.4).round(), Hex::new(0, 1));
+    assert_eq!(FractionalHex::new(0.2, 0.2).round(), Hex::new(0, 0));
+    assert_eq!(FractionalHex::new(-1.6, 0.7).round(), Hex::new(-2, 1));
+    assert_eq!(
+        FractionalHex::from(Hex::new(0, 0)).lerp(Hex::new(4, -2).into(), 0.5),
+        FractionalHex::new(2.0, -1.0)
+    );
+}
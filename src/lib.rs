@@ -1,10 +1,38 @@
-use std::collections::BTreeMap;
+#![forbid(unsafe_code)]
+
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::around_iterator::AroundIterator;
+pub use crate::ascii::ParseAsciiError;
+pub use crate::direction::{Diagonal, Direction, FlatDirection};
+pub use crate::hex::{Cube, Hex, InvalidCube, InvalidDoubled};
+pub use crate::layout::{FractionalHex, Layout, Orientation};
+use crate::line_iterator::LineIterator;
+pub use crate::offset::{OffsetCoord, OffsetLayout};
+use crate::range_iterator::RangeIterator;
+use crate::spiral_iterator::SpiralIterator;
+pub use crate::transform::Axis;
 
 pub mod around_iterator;
+pub mod ascii;
 pub mod astar;
+pub mod dijkstra;
+pub mod direction;
 pub mod direction_iterator;
+pub mod hex;
+pub mod layout;
+pub mod line_iterator;
+pub mod offset;
+pub mod range_iterator;
+#[cfg(feature = "serde")]
+mod serde_impl;
+pub mod shape;
+pub mod spiral_iterator;
+pub mod svg;
+#[cfg(feature = "tiled")]
+pub mod tiled;
+pub mod transform;
+pub mod visibility;
 
 // reads: https://www.redblobgames.com/grids/hexagons/
 
@@ -129,8 +157,236 @@ impl<T> HexGrid<T> {
         }
     }
 
+    /// Builds a grid from rows of items, `rows[row][col]` ends up at the hex of that
+    /// [`OffsetCoord`] in the given layout. See [`offset::from_offset_rows`].
+    /// # Examples
+    /// ```
+    /// use hex_grid::{HexGrid, OffsetCoord, OffsetLayout};
+    /// let grid = HexGrid::from_offset_rows(
+    ///     OffsetLayout::OddR,
+    ///     vec![
+    ///         vec!["A", "B", "C"],
+    ///         vec!["D", "E", "F"],
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(grid.get(0, 0), Some(&"A"));
+    /// assert_eq!(grid.get(0, 1), Some(&"D"));
+    /// assert_eq!(grid.get(2, 1), Some(&"F"));
+    ///
+    /// let (origin, rows) = grid.to_offset_rows(OffsetLayout::OddR);
+    /// assert_eq!(origin, OffsetCoord::new(0, 0));
+    /// assert_eq!(rows[1], vec![Some(&"D"), Some(&"E"), Some(&"F")]);
+    /// ```
+    pub fn from_offset_rows(layout: OffsetLayout, rows: Vec<Vec<T>>) -> Self {
+        offset::from_offset_rows(layout, rows)
+    }
+
+    /// Like [`HexGrid::from_offset_rows`], but `rows[0][0]` ends up at `origin`, which is what
+    /// [`HexGrid::to_offset_rows`] returns. See [`offset::from_offset_rows_at`].
+    pub fn from_offset_rows_at(
+        layout: OffsetLayout,
+        origin: OffsetCoord,
+        rows: Vec<Vec<T>>,
+    ) -> Self {
+        offset::from_offset_rows_at(layout, origin, rows)
+    }
+
+    /// Builds a grid in the shape of a big hexagon, with an item made by `fill` at every
+    /// coordinate within `radius` of `center`. See [`shape::hexagon`].
+    /// # Examples
+    /// ```
+    /// use hex_grid::{Hex, HexGrid};
+    /// let grid = HexGrid::hexagon(1, (0, 0), |hex| hex.length());
+    ///
+    /// assert_eq!(grid.values_range(1, (0, 0)).count(), 7);
+    /// assert_eq!(grid.get(0, 0), Some(&0));
+    /// assert_eq!(grid.get(1, -1), Some(&1));
+    /// ```
+    pub fn hexagon(radius: i32, center: impl Into<Hex>, fill: impl FnMut(Hex) -> T) -> Self {
+        shape::fill(shape::hexagon(radius, center), fill)
+    }
+
+    /// Builds a grid with an item at every coordinate between the two corners.
+    /// See [`shape::parallelogram`].
+    pub fn parallelogram(
+        corner_a: impl Into<Hex>,
+        corner_b: impl Into<Hex>,
+        fill: impl FnMut(Hex) -> T,
+    ) -> Self {
+        shape::fill(shape::parallelogram(corner_a, corner_b), fill)
+    }
+
+    /// Builds a grid in the shape of a triangle with sides of `size + 1` hexes.
+    /// See [`shape::triangle`].
+    pub fn triangle(corner: impl Into<Hex>, size: i32, fill: impl FnMut(Hex) -> T) -> Self {
+        shape::fill(shape::triangle(corner, size), fill)
+    }
+
+    /// Builds a grid of `width` columns and `height` rows in an offset layout.
+    /// See [`shape::rectangle`].
+    /// # Examples
+    /// ```
+    /// use hex_grid::{HexGrid, OffsetLayout};
+    /// let grid = HexGrid::rectangle(OffsetLayout::OddR, 3, 2, |_| ".");
+    ///
+    /// let (_, rows) = grid.to_offset_rows(OffsetLayout::OddR);
+    /// assert_eq!(rows, vec![vec![Some(&"."); 3]; 2]);
+    /// ```
+    pub fn rectangle(
+        layout: OffsetLayout,
+        width: i32,
+        height: i32,
+        fill: impl FnMut(Hex) -> T,
+    ) -> Self {
+        shape::fill(shape::rectangle(layout, width, height), fill)
+    }
+
+    /// Returns the items as rows of an offset layout together with the column and row of
+    /// `rows[0][0]`, the opposite of [`HexGrid::from_offset_rows_at`].
+    /// See [`offset::to_offset_rows`].
+    pub fn to_offset_rows(&self, layout: OffsetLayout) -> (OffsetCoord, Vec<Vec<Option<&T>>>) {
+        offset::to_offset_rows(self, layout)
+    }
+
+    /// Returns a copy of the grid turned around `center` by `steps` times 60 degrees,
+    /// clockwise for positive steps. See [`Hex::rotate_around`].
+    /// # Examples
+    /// ```
+    /// use hex_grid::HexGrid;
+    /// let mut room = HexGrid::new();
+    /// room.set(0, 0, "door");
+    /// room.set(1, 0, "chest");
+    ///
+    /// let turned = room.rotated((0, 0), 1);
+    /// assert_eq!(turned.get(0, 0), Some(&"door"));
+    /// assert_eq!(turned.get(0, 1), Some(&"chest"));
+    /// ```
+    pub fn rotated(&self, center: impl Into<Hex>, steps: i32) -> Self
+    where
+        T: Clone,
+    {
+        transform::rotated(self, center, steps)
+    }
+
+    /// Returns a copy of the grid mirrored across one of the axes through the origin.
+    /// See [`Hex::reflect`].
+    pub fn reflected(&self, axis: Axis) -> Self
+    where
+        T: Clone,
+    {
+        transform::reflected(self, axis)
+    }
+
+    /// Draws the grid as text in the style of the diagram above, with the text from `label`
+    /// in every cell. See [`ascii::to_ascii`].
+    /// # Examples
+    /// ```
+    /// use hex_grid::{HexGrid, Orientation};
+    /// let mut grid = HexGrid::new();
+    /// grid.set(0, 0, 1);
+    /// grid.set(1, 0, 2);
+    ///
+    /// let expected = [
+    ///     r"  / \   / \",
+    ///     r" /   \ /   \",
+    ///     r"|  1  |  2  |",
+    ///     r"|     |     |",
+    ///     r" \   / \   /",
+    ///     r"  \ /   \ /",
+    /// ];
+    /// assert_eq!(grid.to_ascii(Orientation::Pointy, |item| item.to_string()), expected.join("\n"));
+    /// ```
+    pub fn to_ascii<F>(&self, orientation: Orientation, label: F) -> String
+    where
+        F: FnMut(&T) -> String,
+    {
+        ascii::to_ascii(self, orientation, label)
+    }
+
+    /// Reads a grid from text with one character per hex in staggered rows, turning every
+    /// character into an item with `cell`. See [`ascii::parse_ascii`] for the format.
+    /// # Examples
+    /// ```
+    /// use hex_grid::HexGrid;
+    /// let grid = HexGrid::parse_ascii(
+    ///     "
+    ///     . # .
+    ///      . . .
+    ///     ",
+    ///     |character| (character == '.').then_some(1),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(grid.astar((0, 0), (2, 0)).map(|path| path.len()), Some(4));
+    /// ```
+    pub fn parse_ascii<F>(text: &str, cell: F) -> Result<Self, ParseAsciiError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        ascii::parse_ascii(text, cell)
+    }
+
+    /// Draws the grid as a standalone SVG document, `style` decides how every cell looks.
+    /// See [`svg::to_svg`], and [`HexGrid::to_svg_with_options`] for coordinates and paths.
+    /// # Examples
+    /// ```
+    /// use hex_grid::svg::CellStyle;
+    /// use hex_grid::{HexGrid, Layout, Orientation};
+    /// let mut grid = HexGrid::new();
+    /// grid.set(0, 0, "water");
+    /// grid.set(1, 0, "grass");
+    ///
+    /// let layout = Layout::new(Orientation::Pointy, (20.0, 20.0), (0.0, 0.0));
+    /// let svg = grid.to_svg(layout, |_, tile| match *tile {
+    ///     "water" => CellStyle::new("steelblue"),
+    ///     _ => CellStyle::new("olivedrab").with_label(*tile),
+    /// });
+    ///
+    /// assert!(svg.starts_with("<svg"));
+    /// assert_eq!(svg.matches("<polygon").count(), 2);
+    /// ```
+    pub fn to_svg<F>(&self, layout: Layout, style: F) -> String
+    where
+        F: FnMut(Hex, &T) -> svg::CellStyle,
+    {
+        svg::to_svg(self, layout, style)
+    }
+
+    /// Like [`HexGrid::to_svg`], with coordinates and a path drawn on top when asked for in
+    /// `options`. See [`svg::to_svg_with_options`].
+    /// # Examples
+    /// ```
+    /// use hex_grid::svg::{CellStyle, SvgOptions};
+    /// use hex_grid::{Hex, HexGrid, Layout, Orientation};
+    /// let grid = HexGrid::hexagon(1, (0, 0), |_| ());
+    /// let path = grid.astar((-1, 0), (1, 0)).unwrap();
+    ///
+    /// let options = SvgOptions {
+    ///     coordinates: true,
+    ///     path: path.into_iter().map(Hex::from).collect(),
+    ///     ..SvgOptions::default()
+    /// };
+    /// let layout = Layout::new(Orientation::Pointy, (20.0, 20.0), (0.0, 0.0));
+    /// let svg = grid.to_svg_with_options(layout, &options, |_, _| CellStyle::default());
+    ///
+    /// assert_eq!(svg.matches("<text").count(), 7);
+    /// assert_eq!(svg.matches("<polyline").count(), 1);
+    /// ```
+    pub fn to_svg_with_options<F>(
+        &self,
+        layout: Layout,
+        options: &svg::SvgOptions,
+        style: F,
+    ) -> String
+    where
+        F: FnMut(Hex, &T) -> svg::CellStyle,
+    {
+        svg::to_svg_with_options(self, layout, options, style)
+    }
+
     pub fn get(&self, x: i32, y: i32) -> Option<&T> {
-        self.get_by_point(&(x, y))
+        self.get_by_point((x, y))
     }
 
     pub fn get_key_value(&self, x: i32, y: i32) -> Option<(&(i32, i32), &T)> {
@@ -138,58 +394,276 @@ impl<T> HexGrid<T> {
     }
 
     pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
-        self.get_by_point_mut(&(x, y))
+        self.get_by_point_mut((x, y))
     }
 
-    pub fn get_by_point(&self, point: &(i32, i32)) -> Option<&T> {
-        self.data.get(point)
+    pub fn get_by_point(&self, point: impl Into<Hex>) -> Option<&T> {
+        self.data.get(&key(point))
     }
 
-    pub fn get_by_point_mut<'a>(&'a mut self, point: &(i32, i32)) -> Option<&'a mut T> {
-        self.data.get_mut(point)
+    pub fn get_by_point_mut(&mut self, point: impl Into<Hex>) -> Option<&mut T> {
+        self.data.get_mut(&key(point))
+    }
+
+    /// Returns mutable references to the items at the given points, in the order the points are given.
+    ///
+    /// Points without an item are skipped, and so are repeated points, so every returned reference
+    /// points to a different item. This is the building block of all the mutable iterators.
+    ///
+    /// Every stored cell between the smallest and the largest point is walked once, in coordinate
+    /// order, because a map only hands out one mutable borrow at a time. For points spread over
+    /// many columns of `q`, like a ray to the right or a long line, that is every cell in the
+    /// columns in between, so a few points on a wide and full grid cost as much as
+    /// [`HexGrid::iter_mut`].
+    /// # Examples
+    /// ```
+    /// use hex_grid::HexGrid;
+    /// let mut grid = HexGrid::new();
+    /// grid.set(0, 0, 1);
+    /// grid.set(1, 0, 2);
+    /// grid.set(5, 5, 3);
+    ///
+    /// let items = grid.get_many_mut([(5, 5), (0, 0), (2, 2), (5, 5)]);
+    /// assert_eq!(items, vec![((5, 5), &mut 3), ((0, 0), &mut 1)]);
+    /// ```
+    pub fn get_many_mut<I>(&mut self, points: I) -> Vec<((i32, i32), &mut T)>
+    where
+        I: IntoIterator,
+        I::Item: Into<Hex>,
+    {
+        let mut wanted = BTreeMap::new();
+        for point in points {
+            let index = wanted.len();
+            wanted.entry(key(point)).or_insert(index);
+        }
+
+        let (Some((first, _)), Some((last, _))) =
+            (wanted.first_key_value(), wanted.last_key_value())
+        else {
+            return Vec::new();
+        };
+
+        // both are sorted, so the wanted points are matched while walking the cells
+        let mut found: Vec<Option<((i32, i32), &mut T)>> = wanted.values().map(|_| None).collect();
+        let mut wanted = wanted.iter().peekable();
+        for (point, item) in self.data.range_mut(*first..=*last) {
+            while wanted.next_if(|(wanted, _)| *wanted < point).is_some() {}
+            if let Some((_, index)) = wanted.next_if(|(wanted, _)| *wanted == point) {
+                found[*index] = Some((*point, item));
+            }
+        }
+
+        found.into_iter().flatten().collect()
     }
 
     pub fn set(&mut self, x: i32, y: i32, item: T) -> Option<T> {
         self.insert((x, y), item)
     }
 
-    pub fn insert(&mut self, point: (i32, i32), item: T) -> Option<T> {
-        self.data.insert(point, item)
+    pub fn insert(&mut self, point: impl Into<Hex>, item: T) -> Option<T> {
+        self.data.insert(key(point), item)
     }
 
-    /// calculate the distance between two points, this doesn't check if it is possible in the grid.
-    pub fn distance(point_a: &(i32, i32), point_b: &(i32, i32)) -> i32 {
-        let a = to_3d_coordinate(point_a.0, point_a.1);
-        let b = to_3d_coordinate(point_b.0, point_b.1);
+    /// removes the item at a point and returns it.
+    pub fn remove(&mut self, point: impl Into<Hex>) -> Option<T> {
+        self.data.remove(&key(point))
+    }
+
+    pub fn remove_entry(&mut self, point: impl Into<Hex>) -> Option<((i32, i32), T)> {
+        self.data.remove_entry(&key(point))
+    }
+
+    pub fn contains(&self, point: impl Into<Hex>) -> bool {
+        self.data.contains_key(&key(point))
+    }
+
+    /// the number of items in the grid.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.data.clear()
+    }
+
+    /// keeps only the items for which `keep` returns `true`.
+    pub fn retain<F>(&mut self, keep: F)
+    where
+        F: FnMut(&(i32, i32), &mut T) -> bool,
+    {
+        self.data.retain(keep)
+    }
+
+    /// Gets the entry at a point for in-place manipulation, like [`BTreeMap::entry`].
+    /// # Examples
+    /// ```
+    /// use hex_grid::HexGrid;
+    /// let mut visits = HexGrid::new();
+    ///
+    /// for point in [(0, 0), (1, 0), (0, 0)] {
+    ///     *visits.entry(point).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(visits.get(0, 0), Some(&2));
+    /// assert_eq!(visits.get(1, 0), Some(&1));
+    /// ```
+    pub fn entry(
+        &mut self,
+        point: impl Into<Hex>,
+    ) -> std::collections::btree_map::Entry<'_, (i32, i32), T> {
+        self.data.entry(key(point))
+    }
 
-        // abs_max_3d_point((a.0 - b.0, a.1 - b.1, a.2 - b.2))
-        Self::distance_3d(&a, &b)
+    /// gets the item at a cube coordinate, fails when the coordinates don't add up to 0.
+    /// # Examples
+    /// ```
+    /// use hex_grid::HexGrid;
+    /// let mut grid = HexGrid::new();
+    /// grid.insert_cube((2, 1, -3), "A").unwrap();
+    ///
+    /// assert_eq!(grid.get(2, 1), Some(&"A"));
+    /// assert_eq!(grid.get_cube((2, 1, -3)), Ok(Some(&"A")));
+    /// assert!(grid.get_cube((2, 1, 3)).is_err());
+    /// ```
+    pub fn get_cube(&self, cube: (i32, i32, i32)) -> Result<Option<&T>, InvalidCube> {
+        let cube = Cube::try_from(cube)?;
+        Ok(self.get_by_point(cube.to_hex()))
+    }
+
+    /// inserts an item at a cube coordinate, fails when the coordinates don't add up to 0.
+    pub fn insert_cube(
+        &mut self,
+        cube: (i32, i32, i32),
+        item: T,
+    ) -> Result<Option<T>, InvalidCube> {
+        let cube = Cube::try_from(cube)?;
+        Ok(self.insert(cube.to_hex(), item))
+    }
+
+    /// calculate the distance between two points, this doesn't check if it is possible in the grid.
+    pub fn distance(point_a: impl Into<Hex>, point_b: impl Into<Hex>) -> i32 {
+        point_a.into().distance(point_b)
     }
 
     pub fn distance_3d(a: &(i32, i32, i32), b: &(i32, i32, i32)) -> i32 {
         abs_max_3d_point((a.0 - b.0, a.1 - b.1, a.2 - b.2))
     }
 
+    /// calculates the coordinates on the straight line between two points, both points included.
+    /// this doesn't check if the coordinates are in the grid. See [`line_iterator::line`].
+    pub fn line(point_a: impl Into<Hex>, point_b: impl Into<Hex>) -> Vec<(i32, i32)> {
+        line_iterator::line(point_a, point_b)
+    }
+
+    /// checks whether `point_b` can be seen from `point_a`, `blocks` tells which items block the view.
+    /// See [`visibility::has_line_of_sight`] for how the edge between two hexes is handled.
+    pub fn has_line_of_sight<F>(
+        &self,
+        point_a: impl Into<Hex>,
+        point_b: impl Into<Hex>,
+        blocks: F,
+    ) -> bool
+    where
+        F: Fn(&T) -> bool,
+    {
+        visibility::has_line_of_sight(self, point_a, point_b, blocks)
+    }
+
+    /// collects every present cell within `radius` of `origin` that can be seen from `origin`.
+    /// See [`visibility::field_of_view`].
+    pub fn field_of_view<F>(
+        &self,
+        origin: impl Into<Hex>,
+        radius: i32,
+        blocks: F,
+    ) -> BTreeSet<(i32, i32)>
+    where
+        F: Fn(&T) -> bool,
+    {
+        visibility::field_of_view(self, origin, radius, blocks)
+    }
+
     /// calculates the path between two points using astar
-    pub fn astar(&self, point_a: (i32, i32), point_b: (i32, i32)) -> Option<Vec<(i32, i32)>> {
-        astar::astar(&self, point_a, point_b)
+    pub fn astar(
+        &self,
+        point_a: impl Into<Hex>,
+        point_b: impl Into<Hex>,
+    ) -> Option<Vec<(i32, i32)>> {
+        astar::astar(self, point_a, point_b)
+    }
+
+    /// calculates the path between two points using astar, only stepping onto cells for which
+    /// `passable` returns true. See [`astar::astar_where`].
+    pub fn astar_where<F>(
+        &self,
+        point_a: impl Into<Hex>,
+        point_b: impl Into<Hex>,
+        passable: F,
+    ) -> Option<Vec<(i32, i32)>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        astar::astar_where(self, point_a, point_b, passable)
+    }
+
+    /// calculates the cheapest path between two points using astar, `cost` returns the cost of
+    /// stepping from one point onto the next or `None` if that cell can not be entered.
+    /// See [`astar::astar_with_cost`].
+    pub fn astar_with_cost<F>(
+        &self,
+        point_a: impl Into<Hex>,
+        point_b: impl Into<Hex>,
+        cost: F,
+    ) -> Option<(Vec<(i32, i32)>, u32)>
+    where
+        F: FnMut((i32, i32), (i32, i32), &T) -> Option<u32>,
+    {
+        astar::astar_with_cost(self, point_a, point_b, cost)
+    }
+
+    /// like [`HexGrid::astar_with_cost`], with the heuristic scaled by `min_cost`, the cheapest any
+    /// step can be, so fewer cells are searched. See [`astar::astar_with_min_cost`].
+    pub fn astar_with_min_cost<F>(
+        &self,
+        point_a: impl Into<Hex>,
+        point_b: impl Into<Hex>,
+        min_cost: u32,
+        cost: F,
+    ) -> Option<(Vec<(i32, i32)>, u32)>
+    where
+        F: FnMut((i32, i32), (i32, i32), &T) -> Option<u32>,
+    {
+        astar::astar_with_min_cost(self, point_a, point_b, min_cost, cost)
+    }
+
+    /// finds every cell reachable from `start` within the movement `budget`, together with the
+    /// cheapest cost to get there. See [`dijkstra::reachable`].
+    pub fn reachable<F>(&self, start: impl Into<Hex>, budget: u32, cost: F) -> HexGrid<u32>
+    where
+        F: FnMut((i32, i32), (i32, i32), &T) -> Option<u32>,
+    {
+        dijkstra::reachable(self, start, budget, cost)
     }
 
     pub fn iter_direction(
         &self,
-        direction: Direction,
+        direction: impl Into<Direction>,
         start_x: i32,
         start_y: i32,
-    ) -> direction_iterator::DirectionIterator<T> {
+    ) -> direction_iterator::DirectionIterator<'_, T> {
         direction_iterator::DirectionIterator::new(self, direction, start_x, start_y)
     }
 
     pub fn iter_direction_mut(
         &mut self,
-        direction: Direction,
+        direction: impl Into<Direction>,
         start_x: i32,
         start_y: i32,
-    ) -> direction_iterator::DirectionIteratorMut<T> {
+    ) -> direction_iterator::DirectionIteratorMut<'_, T> {
         direction_iterator::DirectionIteratorMut::new(self, direction, start_x, start_y)
     }
 
@@ -231,10 +705,10 @@ impl<T> HexGrid<T> {
     /// ```
     pub fn values_direction(
         &self,
-        direction: Direction,
+        direction: impl Into<Direction>,
         start_x: i32,
         start_y: i32,
-    ) -> direction_iterator::Values<T> {
+    ) -> direction_iterator::Values<'_, T> {
         direction_iterator::Values {
             iter: self.iter_direction(direction, start_x, start_y),
         }
@@ -242,10 +716,10 @@ impl<T> HexGrid<T> {
 
     pub fn values_direction_mut(
         &mut self,
-        direction: Direction,
+        direction: impl Into<Direction>,
         start_x: i32,
         start_y: i32,
-    ) -> direction_iterator::ValuesMut<T> {
+    ) -> direction_iterator::ValuesMut<'_, T> {
         direction_iterator::ValuesMut {
             iter: self.iter_direction_mut(direction, start_x, start_y),
         }
@@ -253,20 +727,96 @@ impl<T> HexGrid<T> {
 
     pub fn keys_direction(
         &self,
-        direction: Direction,
+        direction: impl Into<Direction>,
         start_x: i32,
         start_y: i32,
-    ) -> direction_iterator::Keys<T> {
+    ) -> direction_iterator::Keys<'_, T> {
         direction_iterator::Keys {
             iter: self.iter_direction(direction, start_x, start_y),
         }
     }
 
-    pub fn iter_around(&self, distance: i32, mid_point: (i32, i32)) -> AroundIterator<T> {
-        AroundIterator::new(&self, distance, mid_point)
+    /// Like [`HexGrid::iter_direction`], but jumps to the next hex in a [`Diagonal`] on every step.
+    /// # Examples
+    /// ```
+    /// use hex_grid::{Diagonal, HexGrid};
+    /// let mut grid = HexGrid::new();
+    /// grid.set(0, 0, "A");
+    /// grid.set(1, 0, "B");
+    /// grid.set(1, 1, "C");
+    /// grid.set(2, 2, "D");
+    ///
+    /// let data: Vec<_> = grid.values_diagonal(Diagonal::DownRight, 0, 0).collect();
+    /// assert_eq!(data, vec![&"A", &"C", &"D"]);
+    /// ```
+    pub fn iter_diagonal(
+        &self,
+        diagonal: Diagonal,
+        start_x: i32,
+        start_y: i32,
+    ) -> direction_iterator::DirectionIterator<'_, T> {
+        direction_iterator::DirectionIterator::diagonal(self, diagonal, start_x, start_y)
+    }
+
+    pub fn iter_diagonal_mut(
+        &mut self,
+        diagonal: Diagonal,
+        start_x: i32,
+        start_y: i32,
+    ) -> direction_iterator::DirectionIteratorMut<'_, T> {
+        direction_iterator::DirectionIteratorMut::diagonal(self, diagonal, start_x, start_y)
+    }
+
+    pub fn values_diagonal(
+        &self,
+        diagonal: Diagonal,
+        start_x: i32,
+        start_y: i32,
+    ) -> direction_iterator::Values<'_, T> {
+        direction_iterator::Values {
+            iter: self.iter_diagonal(diagonal, start_x, start_y),
+        }
+    }
+
+    pub fn values_diagonal_mut(
+        &mut self,
+        diagonal: Diagonal,
+        start_x: i32,
+        start_y: i32,
+    ) -> direction_iterator::ValuesMut<'_, T> {
+        direction_iterator::ValuesMut {
+            iter: self.iter_diagonal_mut(diagonal, start_x, start_y),
+        }
+    }
+
+    pub fn keys_diagonal(
+        &self,
+        diagonal: Diagonal,
+        start_x: i32,
+        start_y: i32,
+    ) -> direction_iterator::Keys<'_, T> {
+        direction_iterator::Keys {
+            iter: self.iter_diagonal(diagonal, start_x, start_y),
+        }
+    }
+
+    pub fn iter_around(&self, distance: i32, mid_point: impl Into<Hex>) -> AroundIterator<'_, T> {
+        AroundIterator::new(self, distance, mid_point)
+    }
+
+    pub fn iter_around_mut(
+        &mut self,
+        distance: i32,
+        mid_point: impl Into<Hex>,
+    ) -> around_iterator::AroundIteratorMut<'_, T> {
+        around_iterator::AroundIteratorMut::new(self, distance, mid_point)
     }
 
-    pub fn keys_around(&self, distance: i32, mid_point: (i32, i32)) -> around_iterator::Keys<T> {
+    pub fn keys_around(
+        &self,
+        distance: i32,
+        mid_point: impl Into<Hex>,
+    ) -> around_iterator::Keys<'_, T> {
         around_iterator::Keys {
             iter: self.iter_around(distance, mid_point),
         }
@@ -275,13 +825,230 @@ impl<T> HexGrid<T> {
     pub fn values_around(
         &self,
         distance: i32,
-        mid_point: (i32, i32),
-    ) -> around_iterator::Values<T> {
+        mid_point: impl Into<Hex>,
+    ) -> around_iterator::Values<'_, T> {
         around_iterator::Values {
             iter: self.iter_around(distance, mid_point),
         }
     }
 
+    /// Returns an iterator over mutable references to the items on the ring at exactly
+    /// `distance` of `mid_point`, in the same order as [`HexGrid::values_around`].
+    /// # Examples
+    /// ```
+    /// use hex_grid::HexGrid;
+    /// let mut grid = HexGrid::new();
+    /// grid.set(0, 0, 10);
+    /// grid.set(1, 0, 10);
+    /// grid.set(0, 1, 10);
+    ///
+    /// for health in grid.values_around_mut(1, (0, 0)) {
+    ///     *health -= 3;
+    /// }
+    ///
+    /// assert_eq!(grid.get(0, 0), Some(&10));
+    /// assert_eq!(grid.get(1, 0), Some(&7));
+    /// assert_eq!(grid.get(0, 1), Some(&7));
+    /// ```
+    pub fn values_around_mut(
+        &mut self,
+        distance: i32,
+        mid_point: impl Into<Hex>,
+    ) -> around_iterator::ValuesMut<'_, T> {
+        around_iterator::ValuesMut {
+            iter: self.iter_around_mut(distance, mid_point),
+        }
+    }
+
+    /// Returns an iterator over every present cell within `distance` of `mid_point`, the mid point included.
+    /// Cells are yielded in ascending coordinate order. To visit every coordinate in the range,
+    /// regardless of the grid, use [`range_iterator::RangeCoordinates`].
+    /// # Examples
+    /// ```
+    /// use hex_grid::HexGrid;
+    /// let mut grid = HexGrid::new();
+    /// grid.set(0, 0, "A");
+    /// grid.set(1, 0, "B");
+    /// grid.set(2, 0, "C");
+    /// grid.set(0, 1, "D");
+    ///
+    /// let data: Vec<_> = grid.values_range(1, (0, 0)).collect();
+    /// assert_eq!(data, vec![&"A", &"D", &"B"]);
+    /// let data: Vec<_> = grid.keys_range(0, (2, 0)).collect();
+    /// assert_eq!(data, vec![&(2, 0)]);
+    /// ```
+    pub fn iter_range(&self, distance: i32, mid_point: impl Into<Hex>) -> RangeIterator<'_, T> {
+        RangeIterator::new(self, distance, mid_point)
+    }
+
+    pub fn iter_range_mut(
+        &mut self,
+        distance: i32,
+        mid_point: impl Into<Hex>,
+    ) -> range_iterator::RangeIteratorMut<'_, T> {
+        range_iterator::RangeIteratorMut::new(self, distance, mid_point)
+    }
+
+    pub fn keys_range(
+        &self,
+        distance: i32,
+        mid_point: impl Into<Hex>,
+    ) -> range_iterator::Keys<'_, T> {
+        range_iterator::Keys {
+            iter: self.iter_range(distance, mid_point),
+        }
+    }
+
+    pub fn values_range(
+        &self,
+        distance: i32,
+        mid_point: impl Into<Hex>,
+    ) -> range_iterator::Values<'_, T> {
+        range_iterator::Values {
+            iter: self.iter_range(distance, mid_point),
+        }
+    }
+
+    pub fn values_range_mut(
+        &mut self,
+        distance: i32,
+        mid_point: impl Into<Hex>,
+    ) -> range_iterator::ValuesMut<'_, T> {
+        range_iterator::ValuesMut {
+            iter: self.iter_range_mut(distance, mid_point),
+        }
+    }
+
+    /// Returns an iterator over the present cells closest first, starting at `center` and
+    /// then every ring around it up to `max_radius`.
+    /// The iterator is lazy, so searching for the nearest match stops as soon as one is found.
+    /// # Examples
+    /// ```
+    /// use hex_grid::HexGrid;
+    /// let mut grid = HexGrid::new();
+    /// grid.set(0, 0, "occupied");
+    /// grid.set(1, 0, "occupied");
+    /// grid.set(0, 1, "free");
+    /// grid.set(3, 0, "free");
+    ///
+    /// let nearest_free = grid
+    ///     .iter_spiral((0, 0), 5)
+    ///     .find(|(_, tile)| **tile == "free")
+    ///     .map(|(point, _)| point);
+    /// assert_eq!(nearest_free, Some(&(0, 1)));
+    /// ```
+    pub fn iter_spiral(&self, center: impl Into<Hex>, max_radius: i32) -> SpiralIterator<'_, T> {
+        SpiralIterator::new(self, center, max_radius)
+    }
+
+    pub fn keys_spiral(
+        &self,
+        center: impl Into<Hex>,
+        max_radius: i32,
+    ) -> spiral_iterator::Keys<'_, T> {
+        spiral_iterator::Keys {
+            iter: self.iter_spiral(center, max_radius),
+        }
+    }
+
+    pub fn values_spiral(
+        &self,
+        center: impl Into<Hex>,
+        max_radius: i32,
+    ) -> spiral_iterator::Values<'_, T> {
+        spiral_iterator::Values {
+            iter: self.iter_spiral(center, max_radius),
+        }
+    }
+
+    /// Returns an iterator over the present cells on the straight line from `point_a` to `point_b`.
+    /// Unlike [`HexGrid::iter_direction`] the points don't have to be on one of the six directions,
+    /// and cells without an item are skipped instead of ending the iterator.
+    /// # Examples
+    /// ```
+    /// use hex_grid::HexGrid;
+    /// let mut grid = HexGrid::new();
+    /// grid.set(0, 0, "A");
+    /// grid.set(1, 0, "B");
+    /// grid.set(2, -1, "C");
+    /// grid.set(4, -2, "D");
+    ///
+    /// let data: Vec<_> = grid.values_line((0, 0), (4, -2)).collect();
+    /// assert_eq!(data, vec![&"A", &"B", &"C", &"D"]);
+    /// ```
+    pub fn iter_line(
+        &self,
+        point_a: impl Into<Hex>,
+        point_b: impl Into<Hex>,
+    ) -> LineIterator<'_, T> {
+        LineIterator::new(self, point_a, point_b)
+    }
+
+    pub fn iter_line_mut(
+        &mut self,
+        point_a: impl Into<Hex>,
+        point_b: impl Into<Hex>,
+    ) -> line_iterator::LineIteratorMut<'_, T> {
+        line_iterator::LineIteratorMut::new(self, point_a, point_b)
+    }
+
+    pub fn keys_line(
+        &self,
+        point_a: impl Into<Hex>,
+        point_b: impl Into<Hex>,
+    ) -> line_iterator::Keys<'_, T> {
+        line_iterator::Keys {
+            iter: self.iter_line(point_a, point_b),
+        }
+    }
+
+    pub fn values_line(
+        &self,
+        point_a: impl Into<Hex>,
+        point_b: impl Into<Hex>,
+    ) -> line_iterator::Values<'_, T> {
+        line_iterator::Values {
+            iter: self.iter_line(point_a, point_b),
+        }
+    }
+
+    pub fn values_line_mut(
+        &mut self,
+        point_a: impl Into<Hex>,
+        point_b: impl Into<Hex>,
+    ) -> line_iterator::ValuesMut<'_, T> {
+        line_iterator::ValuesMut {
+            iter: self.iter_line_mut(point_a, point_b),
+        }
+    }
+
+    /// iterates over all items, sorted by their coordinates.
+    pub fn iter(&self) -> std::collections::btree_map::Iter<'_, (i32, i32), T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::collections::btree_map::IterMut<'_, (i32, i32), T> {
+        self.data.iter_mut()
+    }
+
+    pub fn keys(&self) -> std::collections::btree_map::Keys<'_, (i32, i32), T> {
+        self.data.keys()
+    }
+
+    pub fn values(&self) -> std::collections::btree_map::Values<'_, (i32, i32), T> {
+        self.data.values()
+    }
+
+    pub fn values_mut(&mut self) -> std::collections::btree_map::ValuesMut<'_, (i32, i32), T> {
+        self.data.values_mut()
+    }
+
+    /// removes all items and iterates over them, the grid is empty afterwards
+    /// even when the iterator isn't used up.
+    pub fn drain(&mut self) -> std::collections::btree_map::IntoIter<(i32, i32), T> {
+        std::mem::take(&mut self.data).into_iter()
+    }
+
     pub fn into_keys(self) -> std::collections::btree_map::IntoKeys<(i32, i32), T> {
         self.data.into_keys()
     }
@@ -291,43 +1058,18 @@ impl<T> HexGrid<T> {
     }
 }
 
-#[derive(Debug)]
-pub enum Direction {
-    Right,
-    Left,
-    DownRight,
-    DownLeft,
-    UpRight,
-    UpLeft,
-}
-
-impl Direction {
-    fn apply_next(&self, current_x: i32, current_y: i32) -> Option<(i32, i32)> {
-        match self {
-            Direction::Right => current_x.checked_add(1).map(|x| (x, current_y)),
-            Direction::Left => current_x.checked_sub(1).map(|x| (x, current_y)),
-            Direction::DownRight => current_y.checked_add(1).map(|y| (current_x, y)),
-            Direction::UpLeft => current_y.checked_sub(1).map(|y| (current_x, y)),
-            Direction::DownLeft => match (current_x.checked_sub(1), current_y.checked_add(1)) {
-                (Some(x), Some(y)) => Some((x, y)),
-                _ => None,
-            },
-            Direction::UpRight => match (current_x.checked_add(1), current_y.checked_sub(1)) {
-                (Some(x), Some(y)) => Some((x, y)),
-                _ => None,
-            },
-        }
+impl<P: Into<Hex>, T> FromIterator<(P, T)> for HexGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        let mut grid = HexGrid::new();
+        grid.extend(iter);
+        grid
     }
+}
 
-    fn rotate_clockwise(&self) -> Self {
-        match self {
-            Direction::Right => Direction::DownRight,
-            Direction::DownRight => Direction::DownLeft,
-            Direction::DownLeft => Direction::Left,
-            Direction::Left => Direction::UpLeft,
-            Direction::UpLeft => Direction::UpRight,
-            Direction::UpRight => Direction::Right,
-        }
+impl<P: Into<Hex>, T> Extend<(P, T)> for HexGrid<T> {
+    fn extend<I: IntoIterator<Item = (P, T)>>(&mut self, iter: I) {
+        self.data
+            .extend(iter.into_iter().map(|(point, item)| (key(point), item)));
     }
 }
 
@@ -341,11 +1083,38 @@ pub fn to_3d_coordinate(x: i32, y: i32) -> (i32, i32, i32) {
     (x, y, -x - y)
 }
 
+/// the opposite of [`to_3d_coordinate`], the third coordinate is checked instead of dropped.
+pub fn from_3d_coordinate(x: i32, y: i32, z: i32) -> Result<(i32, i32), InvalidCube> {
+    Cube::new(x, y, z).map(|cube| cube.to_hex().into())
+}
+
+/// converts to doubled width coordinates, where every step to the right adds 2 to the column
+/// and rows are shifted by one column, so `column + row` is always even.
+pub fn to_doubled_coordinate(x: i32, y: i32) -> (i32, i32) {
+    (2 * x + y, y)
+}
+
+/// the opposite of [`to_doubled_coordinate`], fails when `column + row` is odd.
+pub fn from_doubled_coordinate(column: i32, row: i32) -> Result<(i32, i32), InvalidDoubled> {
+    if (column - row) % 2 != 0 {
+        return Err(InvalidDoubled { column, row });
+    }
+
+    Ok(((column - row) / 2, row))
+}
+
+/// the key a point is stored under in the grid.
+pub(crate) fn key(point: impl Into<Hex>) -> (i32, i32) {
+    point.into().into()
+}
+
 pub(crate) fn abs_max_3d_point(tuple: (i32, i32, i32)) -> i32 {
     tuple.0.abs().max(tuple.1.abs()).max(tuple.2.abs())
 }
 
 #[cfg(test)]
+// the older tests pass points by reference, which has to keep compiling
+#[allow(clippy::needless_borrows_for_generic_args)]
 mod tests {
     use collection_literals::btree;
 
@@ -395,11 +1164,153 @@ mod tests {
         assert_eq!(expected, grid)
     }
 
+    #[test]
+    fn hex_points_test() {
+        let mut grid = HexGrid::new();
+        grid.insert(Hex::new(1, 2), 5);
+
+        assert_eq!(Some(&5), grid.get_by_point((1, 2)));
+        assert_eq!(Some(&5), grid.get_by_point(Hex::new(1, 2)));
+        if let Some(item) = grid.get_by_point_mut(Hex::new(1, 2)) {
+            *item = 7;
+        }
+        assert_eq!(grid.get(1, 2), Some(&7));
+    }
+
+    #[test]
+    fn get_many_mut_sparse_test() {
+        // a few cells spread over a wide grid, with a dense column in between
+        let mut grid = HexGrid::new();
+        for r in -500..500 {
+            grid.set(0, r, 0);
+        }
+        grid.set(-1000, 3, 1);
+        grid.set(1000, -3, 2);
+        grid.set(999, 7, 3);
+
+        let items = grid.get_many_mut([(1000, -3), (0, 499), (-1000, 3), (5, 5), (0, -500)]);
+        assert_eq!(
+            items,
+            vec![
+                ((1000, -3), &mut 2),
+                ((0, 499), &mut 0),
+                ((-1000, 3), &mut 1),
+                ((0, -500), &mut 0),
+            ]
+        );
+
+        for q in 995..1000 {
+            grid.set(q, -3, 0);
+        }
+        for value in grid.values_direction_mut(Direction::Right, 995, -3) {
+            *value += 10;
+        }
+        assert_eq!(grid.get(995, -3), Some(&10));
+        assert_eq!(grid.get(1000, -3), Some(&12));
+        assert_eq!(grid.get(999, 7), Some(&3));
+        assert_eq!(grid.get(0, -3), Some(&0));
+    }
+
+    #[test]
+    fn map_api_test() {
+        let mut grid: HexGrid<i32> = [((0, 0), 1), ((1, 0), 2), ((0, 1), 3)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(grid.len(), 3);
+        assert!(grid.contains((1, 0)));
+        assert_eq!(grid.remove((1, 0)), Some(2));
+        assert_eq!(grid.remove((1, 0)), None);
+        assert!(!grid.contains(Hex::new(1, 0)));
+        assert_eq!(grid.remove_entry((0, 1)), Some(((0, 1), 3)));
+
+        grid.extend([(Hex::new(2, 2), 4), (Hex::new(3, 3), 5)]);
+        for value in grid.values_mut() {
+            *value *= 10;
+        }
+        for (_, value) in &mut grid {
+            *value += 1;
+        }
+        assert_eq!(
+            grid.keys().collect::<Vec<_>>(),
+            vec![&(0, 0), &(2, 2), &(3, 3)]
+        );
+        assert_eq!(grid.values().sum::<i32>(), 11 + 41 + 51);
+        assert_eq!((&grid).into_iter().count(), grid.iter().count());
+
+        grid.retain(|hex, value| {
+            *value -= 1;
+            hex.0 > 0
+        });
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            vec![(&(2, 2), &40), (&(3, 3), &50)]
+        );
+
+        *grid.entry((2, 2)).or_insert(0) += 2;
+        grid.entry((4, 4)).or_insert(7);
+        assert_eq!(grid.get(2, 2), Some(&42));
+        assert_eq!(grid.get(4, 4), Some(&7));
+
+        let drained: Vec<_> = grid.drain().collect();
+        assert_eq!(drained.len(), 3);
+        assert!(grid.is_empty());
+
+        grid.insert((0, 0), 1);
+        grid.clear();
+        assert_eq!(grid.len(), 0);
+    }
+
     #[test]
     fn to_3d_coordinate_test() {
         assert_eq!(to_3d_coordinate(2, 1), (2, 1, -3));
     }
 
+    #[test]
+    fn from_3d_coordinate_test() {
+        assert_eq!(from_3d_coordinate(2, 1, -3), Ok((2, 1)));
+        assert_eq!(
+            from_3d_coordinate(2, 1, 3),
+            Err(InvalidCube { q: 2, r: 1, s: 3 })
+        );
+    }
+
+    #[test]
+    fn doubled_coordinate_test() {
+        assert_eq!(to_doubled_coordinate(2, 1), (5, 1));
+        assert_eq!(to_doubled_coordinate(-1, -2), (-4, -2));
+        assert_eq!(from_doubled_coordinate(5, 1), Ok((2, 1)));
+        assert_eq!(from_doubled_coordinate(-4, -2), Ok((-1, -2)));
+        assert_eq!(
+            from_doubled_coordinate(4, 1),
+            Err(InvalidDoubled { column: 4, row: 1 })
+        );
+        assert_eq!(
+            from_doubled_coordinate(-3, 0),
+            Err(InvalidDoubled { column: -3, row: 0 })
+        );
+    }
+
+    #[test]
+    fn cube_access_test() {
+        let mut grid = HexGrid::new();
+
+        assert_eq!(grid.insert_cube((1, 2, -3), 'A'), Ok(None));
+        assert_eq!(grid.insert_cube((1, 2, -3), 'B'), Ok(Some('A')));
+        assert_eq!(
+            grid.insert_cube((1, 2, 3), 'C'),
+            Err(InvalidCube { q: 1, r: 2, s: 3 })
+        );
+
+        assert_eq!(grid.get_cube((1, 2, -3)), Ok(Some(&'B')));
+        assert_eq!(grid.get_cube((0, 0, 0)), Ok(None));
+        assert_eq!(
+            grid.get_cube((1, 2, 0)),
+            Err(InvalidCube { q: 1, r: 2, s: 0 })
+        );
+        assert_eq!(grid, HexGrid::from(btree! {(1, 2) => 'B'}));
+    }
+
     #[test]
     fn astar_knightsofu_test() {
         // based on https://theknightsofu.com/pathfinding-on-a-hexagonal-grid-a-algorithm-2/
@@ -0,0 +1,184 @@
+use crate::{FractionalHex, Hex, HexGrid};
+
+pub struct Values<'a, T> {
+    pub(crate) iter: LineIterator<'a, T>,
+}
+
+impl<'a, T> Iterator for Values<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, v)| v)
+    }
+}
+
+pub struct Keys<'a, T> {
+    pub(crate) iter: LineIterator<'a, T>,
+}
+
+impl<'a, T> Iterator for Keys<'a, T> {
+    type Item = &'a (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(k, _)| k)
+    }
+}
+
+pub struct ValuesMut<'a, T> {
+    pub(crate) iter: LineIteratorMut<'a, T>,
+}
+
+impl<'a, T> Iterator for ValuesMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, v)| v)
+    }
+}
+
+/// Iterates over the present cells on the line between two points, see [`line()`].
+/// Cells without an item are skipped, the iterator does not stop at them.
+pub struct LineIterator<'a, T> {
+    grid: &'a HexGrid<T>,
+    points: std::vec::IntoIter<(i32, i32)>,
+}
+
+impl<'a, T> LineIterator<'a, T> {
+    pub fn new(grid: &'a HexGrid<T>, point_a: impl Into<Hex>, point_b: impl Into<Hex>) -> Self {
+        LineIterator {
+            grid,
+            points: line(point_a, point_b).into_iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for LineIterator<'a, T> {
+    type Item = (&'a (i32, i32), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.points
+            .by_ref()
+            .find_map(|(x, y)| self.grid.get_key_value(x, y))
+    }
+}
+
+/// Mutable version of [`LineIterator`], the items come from [`HexGrid::get_many_mut`] which walks
+/// every cell in the columns between the two end points.
+pub struct LineIteratorMut<'a, T> {
+    iter: std::vec::IntoIter<((i32, i32), &'a mut T)>,
+}
+
+impl<'a, T> LineIteratorMut<'a, T> {
+    pub fn new(grid: &'a mut HexGrid<T>, point_a: impl Into<Hex>, point_b: impl Into<Hex>) -> Self {
+        LineIteratorMut {
+            iter: grid.get_many_mut(line(point_a, point_b)).into_iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for LineIteratorMut<'a, T> {
+    type Item = ((i32, i32), &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+/// the offset that is added to the start of a line, so the line never lies exactly on the edge
+/// between two hexes. Rounding an exact tie is deterministic, but which side it picks changes along
+/// the line, the nudge breaks every tie consistently toward the same side.
+pub(crate) const NUDGE: f64 = 1e-6;
+
+/// calculates the coordinates on the straight line from `point_a` to `point_b`, both included.
+///
+/// The line is drawn by interpolating between the 3d coordinates and rounding back to the
+/// nearest hex. When the line runs exactly along an edge between two hexes, the tie is broken
+/// by nudging the start of the line a tiny bit towards larger x and y coordinates.
+pub fn line(point_a: impl Into<Hex>, point_b: impl Into<Hex>) -> Vec<(i32, i32)> {
+    line_nudged(point_a, point_b, NUDGE)
+        .into_iter()
+        .map(Hex::into)
+        .collect()
+}
+
+/// like [`line()`], but pushes the line by `nudge` so ties are broken to one side or the other.
+pub(crate) fn line_nudged(
+    point_a: impl Into<Hex>,
+    point_b: impl Into<Hex>,
+    nudge: f64,
+) -> Vec<Hex> {
+    let point_a = point_a.into();
+    let point_b = point_b.into();
+    let steps = point_a.distance(point_b);
+    // not parallel to any line that runs along the edges, so every tie is broken,
+    // the third coordinate moves by `-3.0 * nudge`
+    let a = FractionalHex::new(point_a.q as f64 + nudge, point_a.r as f64 + 2.0 * nudge);
+    let b = FractionalHex::from(point_b);
+
+    (0..=steps)
+        .map(|step| {
+            let t = if steps == 0 {
+                0.0
+            } else {
+                step as f64 / steps as f64
+            };
+            a.lerp(b, t).round()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+use collection_literals::btree;
+
+#[test]
+fn line_test() {
+    assert_eq!(line((1, 1), (1, 1)), vec![(1, 1)]);
+    assert_eq!(line((0, 0), (3, 0)), vec![(0, 0), (1, 0), (2, 0), (3, 0)]);
+    assert_eq!(line((0, 0), (0, -2)), vec![(0, 0), (0, -1), (0, -2)]);
+    assert_eq!(
+        line((0, 0), (4, -2)),
+        vec![(0, 0), (1, 0), (2, -1), (3, -1), (4, -2)]
+    );
+    assert_eq!(
+        line((-2, 3), (3, -1)),
+        vec![(-2, 3), (-1, 2), (0, 1), (1, 1), (2, 0), (3, -1)]
+    );
+}
+
+#[test]
+fn line_is_connected() {
+    for b in [(5, -3), (-4, 1), (2, 6), (-7, -2), (3, 3)] {
+        let points = line((1, 2), b);
+        assert_eq!(points.len() as i32, HexGrid::<()>::distance((1, 2), b) + 1);
+        for pair in points.windows(2) {
+            assert_eq!(HexGrid::<()>::distance(pair[0], pair[1]), 1);
+        }
+    }
+}
+
+#[test]
+fn line_iterator_test() {
+    //
+    //     A B   D
+    let mut grid = HexGrid::from(btree! {
+        (0, 0) => 1,
+        (1, 0) => 2,
+        (3, 0) => 4,
+        (1, 1) => 5,
+    });
+
+    let out: Vec<_> = LineIterator::new(&grid, (0, 0), (3, 0)).collect();
+    assert_eq!(out, vec![(&(0, 0), &1), (&(1, 0), &2), (&(3, 0), &4)]);
+
+    for value in grid.values_line_mut((3, 0), (1, 0)) {
+        *value *= 10;
+    }
+
+    let expected = HexGrid::from(btree! {
+        (0, 0) => 1,
+        (1, 0) => 20,
+        (3, 0) => 40,
+        (1, 1) => 5,
+    });
+    assert_eq!(expected, grid);
+}
@@ -0,0 +1,247 @@
+use crate::{Hex, HexGrid};
+
+/// The four common ways to lay out a hex grid in rows and columns.
+///
+/// `OddR` and `EvenR` shove every odd or even row half a hex to the right, which fits the
+/// pointy layout of [`HexGrid`]. `OddQ` and `EvenQ` shove every odd or even column half a hex
+/// down, which fits a flat layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OffsetLayout {
+    OddR,
+    EvenR,
+    OddQ,
+    EvenQ,
+}
+
+/// A position as a column and a row in one of the [`OffsetLayout`]s.
+/// # Examples
+/// ```
+/// use hex_grid::{Hex, OffsetCoord, OffsetLayout};
+/// let offset = OffsetCoord::new(3, 1);
+///
+/// assert_eq!(offset.to_hex(OffsetLayout::OddR), Hex::new(3, 1));
+/// assert_eq!(offset.to_hex(OffsetLayout::EvenR), Hex::new(2, 1));
+/// assert_eq!(Hex::new(2, 1).to_offset(OffsetLayout::EvenR), offset);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OffsetCoord {
+    pub col: i32,
+    pub row: i32,
+}
+
+impl OffsetCoord {
+    pub const fn new(col: i32, row: i32) -> Self {
+        OffsetCoord { col, row }
+    }
+
+    pub fn from_hex(hex: Hex, layout: OffsetLayout) -> Self {
+        let Hex { q, r } = hex;
+        match layout {
+            OffsetLayout::OddR => OffsetCoord::new(q + (r - (r & 1)) / 2, r),
+            OffsetLayout::EvenR => OffsetCoord::new(q + (r + (r & 1)) / 2, r),
+            OffsetLayout::OddQ => OffsetCoord::new(q, r + (q - (q & 1)) / 2),
+            OffsetLayout::EvenQ => OffsetCoord::new(q, r + (q + (q & 1)) / 2),
+        }
+    }
+
+    pub fn to_hex(self, layout: OffsetLayout) -> Hex {
+        let OffsetCoord { col, row } = self;
+        match layout {
+            OffsetLayout::OddR => Hex::new(col - (row - (row & 1)) / 2, row),
+            OffsetLayout::EvenR => Hex::new(col - (row + (row & 1)) / 2, row),
+            OffsetLayout::OddQ => Hex::new(col, row - (col - (col & 1)) / 2),
+            OffsetLayout::EvenQ => Hex::new(col, row - (col + (col & 1)) / 2),
+        }
+    }
+}
+
+impl From<(i32, i32)> for OffsetCoord {
+    fn from((col, row): (i32, i32)) -> Self {
+        OffsetCoord::new(col, row)
+    }
+}
+
+impl From<OffsetCoord> for (i32, i32) {
+    fn from(offset: OffsetCoord) -> Self {
+        (offset.col, offset.row)
+    }
+}
+
+/// builds a grid from rows of items, `rows[row][col]` ends up at the hex of that
+/// [`OffsetCoord`] in the given layout. Rows don't have to be of the same length.
+pub fn from_offset_rows<T>(layout: OffsetLayout, rows: Vec<Vec<T>>) -> HexGrid<T> {
+    from_offset_rows_at(layout, OffsetCoord::default(), rows)
+}
+
+/// like [`from_offset_rows`], but `rows[0][0]` ends up at `origin` instead of at column and row 0.
+pub fn from_offset_rows_at<T>(
+    layout: OffsetLayout,
+    origin: OffsetCoord,
+    rows: Vec<Vec<T>>,
+) -> HexGrid<T> {
+    let mut grid = HexGrid::new();
+    for (row, items) in rows.into_iter().enumerate() {
+        for (col, item) in items.into_iter().enumerate() {
+            grid.insert(
+                OffsetCoord::new(origin.col + col as i32, origin.row + row as i32).to_hex(layout),
+                item,
+            );
+        }
+    }
+    grid
+}
+
+/// the opposite of [`from_offset_rows_at`], every row is as long as the widest row
+/// and holds `None` where the grid has no item.
+///
+/// The rows start at the smallest column and row of the grid, which is returned together with
+/// the rows, so items at a negative column or row are kept. An empty grid has no rows.
+pub fn to_offset_rows<T>(
+    hexgrid: &HexGrid<T>,
+    layout: OffsetLayout,
+) -> (OffsetCoord, Vec<Vec<Option<&T>>>) {
+    let cells: Vec<_> = hexgrid
+        .data
+        .iter()
+        .map(|(hex, item)| (Hex::from(*hex).to_offset(layout), item))
+        .collect();
+
+    let (Some(min_col), Some(min_row)) = (
+        cells.iter().map(|(offset, _)| offset.col).min(),
+        cells.iter().map(|(offset, _)| offset.row).min(),
+    ) else {
+        return (OffsetCoord::default(), Vec::new());
+    };
+    let origin = OffsetCoord::new(min_col, min_row);
+
+    let width = cells
+        .iter()
+        .map(|(offset, _)| offset.col - min_col + 1)
+        .max()
+        .unwrap_or(0);
+    let height = cells
+        .iter()
+        .map(|(offset, _)| offset.row - min_row + 1)
+        .max()
+        .unwrap_or(0);
+
+    let mut rows: Vec<Vec<Option<&T>>> = (0..height)
+        .map(|_| (0..width).map(|_| None).collect())
+        .collect();
+    for (offset, item) in cells {
+        rows[(offset.row - min_row) as usize][(offset.col - min_col) as usize] = Some(item);
+    }
+    (origin, rows)
+}
+
+#[test]
+fn offset_round_trip_test() {
+    let layouts = [
+        OffsetLayout::OddR,
+        OffsetLayout::EvenR,
+        OffsetLayout::OddQ,
+        OffsetLayout::EvenQ,
+    ];
+
+    for layout in layouts {
+        for q in -5..5 {
+            for r in -5..5 {
+                let hex = Hex::new(q, r);
+                assert_eq!(hex.to_offset(layout).to_hex(layout), hex, "{layout:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn offset_to_hex_test() {
+    assert_eq!(
+        OffsetCoord::new(2, 0).to_hex(OffsetLayout::OddR),
+        Hex::new(2, 0)
+    );
+    assert_eq!(
+        OffsetCoord::new(2, 1).to_hex(OffsetLayout::OddR),
+        Hex::new(2, 1)
+    );
+    assert_eq!(
+        OffsetCoord::new(2, 2).to_hex(OffsetLayout::OddR),
+        Hex::new(1, 2)
+    );
+    assert_eq!(
+        OffsetCoord::new(2, 1).to_hex(OffsetLayout::EvenR),
+        Hex::new(1, 1)
+    );
+    assert_eq!(
+        OffsetCoord::new(2, 2).to_hex(OffsetLayout::EvenR),
+        Hex::new(1, 2)
+    );
+
+    // in the column layouts every column is a straight line down
+    assert_eq!(
+        OffsetCoord::new(1, 2).to_hex(OffsetLayout::OddQ),
+        Hex::new(1, 2)
+    );
+    assert_eq!(
+        OffsetCoord::new(2, 2).to_hex(OffsetLayout::OddQ),
+        Hex::new(2, 1)
+    );
+    assert_eq!(
+        OffsetCoord::new(1, 2).to_hex(OffsetLayout::EvenQ),
+        Hex::new(1, 1)
+    );
+    assert_eq!(
+        OffsetCoord::new(2, 2).to_hex(OffsetLayout::EvenQ),
+        Hex::new(2, 1)
+    );
+}
+
+#[test]
+fn offset_rows_test() {
+    let rows = vec![vec![1, 2, 3], vec![4, 5], vec![6, 7, 8]];
+
+    for layout in [
+        OffsetLayout::OddR,
+        OffsetLayout::EvenR,
+        OffsetLayout::OddQ,
+        OffsetLayout::EvenQ,
+    ] {
+        let grid = HexGrid::from_offset_rows(layout, rows.clone());
+        assert_eq!(
+            grid.to_offset_rows(layout),
+            (
+                OffsetCoord::new(0, 0),
+                vec![
+                    vec![Some(&1), Some(&2), Some(&3)],
+                    vec![Some(&4), Some(&5), None],
+                    vec![Some(&6), Some(&7), Some(&8)],
+                ]
+            )
+        );
+    }
+
+    let empty = HexGrid::<i32>::new();
+    assert!(empty.to_offset_rows(OffsetLayout::OddR).1.is_empty());
+}
+
+#[test]
+fn offset_rows_negative_test() {
+    for layout in [
+        OffsetLayout::OddR,
+        OffsetLayout::EvenR,
+        OffsetLayout::OddQ,
+        OffsetLayout::EvenQ,
+    ] {
+        let grid = HexGrid::hexagon(2, (0, 0), |hex| hex.q * 10 + hex.r);
+        let (origin, rows) = grid.to_offset_rows(layout);
+        assert_eq!(origin, OffsetCoord::new(-2, -2), "{layout:?}");
+        assert_eq!(rows.iter().flatten().flatten().count(), 19, "{layout:?}");
+
+        let read_back: HexGrid<i32> = HexGrid::from_offset_rows_at(layout, origin, rows)
+            .into_iter()
+            .filter_map(|(point, item)| Some((point, *item?)))
+            .collect();
+        assert_eq!(read_back, grid, "{layout:?}");
+    }
+}
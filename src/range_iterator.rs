@@ -0,0 +1,231 @@
+use crate::{Hex, HexGrid, key};
+use std::collections::btree_map;
+
+pub struct Values<'a, T> {
+    pub(crate) iter: RangeIterator<'a, T>,
+}
+
+impl<'a, T> Iterator for Values<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, v)| v)
+    }
+}
+
+pub struct ValuesMut<'a, T> {
+    pub(crate) iter: RangeIteratorMut<'a, T>,
+}
+
+impl<'a, T> Iterator for ValuesMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, v)| v)
+    }
+}
+
+pub struct Keys<'a, T> {
+    pub(crate) iter: RangeIterator<'a, T>,
+}
+
+impl<'a, T> Iterator for Keys<'a, T> {
+    type Item = &'a (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(k, _)| k)
+    }
+}
+
+/// Iterates over every present cell within `distance` of the mid point, the mid point included.
+///
+/// Cells are yielded in ascending coordinate order, the same order as the grid itself is stored in.
+pub struct RangeIterator<'a, T> {
+    grid: &'a HexGrid<T>,
+    distance: i32,
+    mid_point: (i32, i32),
+    next_column: i32,
+    column: Option<btree_map::Range<'a, (i32, i32), T>>,
+}
+
+impl<'a, T> RangeIterator<'a, T> {
+    pub fn new(grid: &'a HexGrid<T>, distance: i32, mid_point: impl Into<Hex>) -> Self {
+        RangeIterator {
+            grid,
+            distance,
+            mid_point: key(mid_point),
+            next_column: -distance,
+            column: None,
+        }
+    }
+}
+
+impl<'a, T> Iterator for RangeIterator<'a, T> {
+    type Item = (&'a (i32, i32), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.column.as_mut().and_then(|column| column.next()) {
+                return Some(item);
+            }
+
+            if self.next_column > self.distance {
+                return None;
+            }
+
+            let (start, end) = column_bounds(self.distance, self.mid_point, self.next_column);
+            self.column = Some(self.grid.data.range(start..=end));
+            self.next_column += 1;
+        }
+    }
+}
+
+/// Mutable version of [`RangeIterator`], the items come from [`HexGrid::get_many_mut`] which walks
+/// every cell in the columns the range covers, including the rows above and below it.
+pub struct RangeIteratorMut<'a, T> {
+    iter: std::vec::IntoIter<((i32, i32), &'a mut T)>,
+}
+
+impl<'a, T> RangeIteratorMut<'a, T> {
+    pub fn new(grid: &'a mut HexGrid<T>, distance: i32, mid_point: impl Into<Hex>) -> Self {
+        RangeIteratorMut {
+            iter: grid
+                .get_many_mut(RangeCoordinates::new(distance, mid_point))
+                .into_iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for RangeIteratorMut<'a, T> {
+    type Item = ((i32, i32), &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+/// Iterates over every coordinate within `distance` of the mid point, regardless of whether a
+/// grid has an item there.
+///
+/// Coordinates are yielded in the same order as [`RangeIterator`].
+#[derive(Debug, Clone)]
+pub struct RangeCoordinates {
+    distance: i32,
+    mid_point: (i32, i32),
+    current: (i32, i32),
+}
+
+impl RangeCoordinates {
+    pub fn new(distance: i32, mid_point: impl Into<Hex>) -> Self {
+        RangeCoordinates {
+            distance,
+            mid_point: key(mid_point),
+            current: (-distance, (-distance).max(0)),
+        }
+    }
+}
+
+impl Iterator for RangeCoordinates {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (dq, dr) = self.current;
+        if dq > self.distance {
+            return None;
+        }
+
+        if dr < self.distance.min(self.distance - dq) {
+            self.current = (dq, dr + 1);
+        } else {
+            let next_dq = dq + 1;
+            self.current = (next_dq, (-self.distance).max(-self.distance - next_dq));
+        }
+
+        Some((self.mid_point.0 + dq, self.mid_point.1 + dr))
+    }
+}
+
+/// the first and last coordinate of the column `dq` columns away from the mid point.
+fn column_bounds(distance: i32, mid_point: (i32, i32), dq: i32) -> ((i32, i32), (i32, i32)) {
+    let q = mid_point.0 + dq;
+    let r_min = mid_point.1 + (-distance).max(-distance - dq);
+    let r_max = mid_point.1 + distance.min(distance - dq);
+
+    ((q, r_min), (q, r_max))
+}
+
+#[cfg(test)]
+use collection_literals::btree;
+
+#[test]
+fn range_coordinates_test() {
+    let out: Vec<_> = RangeCoordinates::new(0, (3, 4)).collect();
+    assert_eq!(out, vec![(3, 4)]);
+
+    let out: Vec<_> = RangeCoordinates::new(1, (0, 0)).collect();
+    assert_eq!(
+        out,
+        vec![(-1, 0), (-1, 1), (0, -1), (0, 0), (0, 1), (1, -1), (1, 0)]
+    );
+
+    assert_eq!(RangeCoordinates::new(3, (5, -2)).count(), 37);
+    assert!(
+        RangeCoordinates::new(3, (5, -2)).all(|point| HexGrid::<()>::distance(point, (5, -2)) <= 3)
+    );
+    assert_eq!(RangeCoordinates::new(-1, (0, 0)).count(), 0);
+}
+
+#[test]
+fn range_iterator_test() {
+    //
+    //     A B C
+    //      D
+    //   F E
+    let grid = HexGrid::from(btree! {
+        (0, 0) => "A",
+        (1, 0) => "B",
+        (2, 0) => "C",
+        (0, 1) => "D",
+        (-1, 2) => "E",
+        (-2, 2) => "F",
+    });
+
+    let out: Vec<_> = RangeIterator::new(&grid, 0, (0, 0)).collect();
+    assert_eq!(out, vec![(&(0, 0), &"A")]);
+
+    let out: Vec<_> = RangeIterator::new(&grid, 1, (0, 0)).collect();
+    assert_eq!(out, vec![(&(0, 0), &"A"), (&(0, 1), &"D"), (&(1, 0), &"B")]);
+
+    let out: Vec<_> = RangeIterator::new(&grid, 2, (0, 0)).collect();
+    let all: Vec<_> = grid.data.iter().collect();
+    assert_eq!(out, all);
+
+    let expected: Vec<_> = RangeCoordinates::new(1, (1, 0))
+        .filter_map(|point| grid.get_key_value(point.0, point.1))
+        .collect();
+    let out: Vec<_> = RangeIterator::new(&grid, 1, (1, 0)).collect();
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn range_iterator_mut_test() {
+    let mut grid = HexGrid::from(btree! {
+        (0, 0) => 1,
+        (1, 0) => 2,
+        (2, 0) => 3,
+        (0, 1) => 4,
+    });
+
+    for value in grid.values_range_mut(1, (0, 0)) {
+        *value *= 10;
+    }
+
+    let expected = HexGrid::from(btree! {
+        (0, 0) => 10,
+        (1, 0) => 20,
+        (2, 0) => 3,
+        (0, 1) => 40,
+    });
+
+    assert_eq!(expected, grid);
+}
@@ -0,0 +1,161 @@
+//! Serialization of the grid, only compiled with the `serde` feature.
+//!
+//! Human readable formats like JSON get a list of `{"q": 0, "r": 0, "value": ...}` objects,
+//! because tuple keys can't be the keys of a JSON object. Binary formats get a list of
+//! `(q, r, value)` tuples.
+
+use crate::{Cube, Hex, HexGrid, InvalidCube};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// the form a [`Cube`] takes in serialized data, it is checked when converted back.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CubeRepr {
+    q: i32,
+    r: i32,
+    s: i32,
+}
+
+impl From<Cube> for CubeRepr {
+    fn from(cube: Cube) -> Self {
+        CubeRepr {
+            q: cube.q(),
+            r: cube.r(),
+            s: cube.s(),
+        }
+    }
+}
+
+impl TryFrom<CubeRepr> for Cube {
+    type Error = InvalidCube;
+
+    fn try_from(repr: CubeRepr) -> Result<Self, Self::Error> {
+        Cube::new(repr.q, repr.r, repr.s)
+    }
+}
+
+#[derive(Serialize)]
+struct Cell<'a, T> {
+    q: i32,
+    r: i32,
+    value: &'a T,
+}
+
+#[derive(Deserialize)]
+struct OwnedCell<T> {
+    q: i32,
+    r: i32,
+    value: T,
+}
+
+impl<T: Serialize> Serialize for HexGrid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_seq(self.iter().map(|(&(q, r), value)| Cell { q, r, value }))
+        } else {
+            serializer.collect_seq(self.iter().map(|(&(q, r), value)| (q, r, value)))
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for HexGrid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let cells: Vec<(i32, i32, T)> = if deserializer.is_human_readable() {
+            Vec::<OwnedCell<T>>::deserialize(deserializer)?
+                .into_iter()
+                .map(|cell| (cell.q, cell.r, cell.value))
+                .collect()
+        } else {
+            Vec::deserialize(deserializer)?
+        };
+
+        let mut grid = HexGrid::new();
+        for (q, r, value) in cells {
+            if grid.insert(Hex::new(q, r), value).is_some() {
+                return Err(D::Error::custom(format!("duplicate hex {q},{r}")));
+            }
+        }
+        Ok(grid)
+    }
+}
+
+#[cfg(test)]
+use crate::Direction;
+#[cfg(test)]
+use collection_literals::btree;
+#[cfg(test)]
+use serde_test::{Configure, Token, assert_tokens};
+
+#[test]
+fn grid_json_test() {
+    let grid = HexGrid::from(btree! {
+        (0, 0) => "A",
+        (-1, 2) => "B",
+    });
+
+    let json = serde_json::to_string(&grid).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"q":-1,"r":2,"value":"B"},{"q":0,"r":0,"value":"A"}]"#
+    );
+    assert_eq!(serde_json::from_str::<HexGrid<&str>>(&json).unwrap(), grid);
+
+    let duplicate = r#"[{"q":0,"r":0,"value":1},{"q":0,"r":0,"value":2}]"#;
+    assert!(serde_json::from_str::<HexGrid<i32>>(duplicate).is_err());
+}
+
+#[test]
+fn grid_compact_test() {
+    let grid = HexGrid::from(btree! {
+        (0, 0) => 5u8,
+        (1, -1) => 6u8,
+    });
+
+    assert_tokens(
+        &grid.compact(),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Tuple { len: 3 },
+            Token::I32(0),
+            Token::I32(0),
+            Token::U8(5),
+            Token::TupleEnd,
+            Token::Tuple { len: 3 },
+            Token::I32(1),
+            Token::I32(-1),
+            Token::U8(6),
+            Token::TupleEnd,
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+fn coordinate_json_test() {
+    let hex = Hex::new(3, -1);
+    assert_eq!(serde_json::to_string(&hex).unwrap(), r#"{"q":3,"r":-1}"#);
+    assert_eq!(
+        serde_json::from_str::<Hex>(r#"{"q":3,"r":-1}"#).unwrap(),
+        hex
+    );
+
+    let cube = hex.to_cube();
+    assert_eq!(
+        serde_json::to_string(&cube).unwrap(),
+        r#"{"q":3,"r":-1,"s":-2}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Cube>(r#"{"q":3,"r":-1,"s":-2}"#).unwrap(),
+        cube
+    );
+    assert!(serde_json::from_str::<Cube>(r#"{"q":3,"r":-1,"s":0}"#).is_err());
+
+    assert_eq!(
+        serde_json::to_string(&Direction::UpLeft).unwrap(),
+        r#""UpLeft""#
+    );
+    assert_eq!(
+        serde_json::from_str::<Direction>(r#""DownRight""#).unwrap(),
+        Direction::DownRight
+    );
+}
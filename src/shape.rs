@@ -0,0 +1,225 @@
+use crate::range_iterator::RangeCoordinates;
+use crate::{Hex, HexGrid, OffsetCoord, OffsetLayout};
+
+/// every coordinate within `radius` of `center`, which makes a big hexagon.
+pub fn hexagon(radius: i32, center: impl Into<Hex>) -> Hexagon {
+    Hexagon::new(radius, center)
+}
+
+/// every coordinate with a `q` and `r` between those of the two corners, both included.
+pub fn parallelogram(corner_a: impl Into<Hex>, corner_b: impl Into<Hex>) -> Parallelogram {
+    Parallelogram::new(corner_a, corner_b)
+}
+
+/// every coordinate `corner + (q, r)` with `q` and `r` at least 0 and `q + r` at most `size`,
+/// so the sides are `size + 1` hexes long.
+pub fn triangle(corner: impl Into<Hex>, size: i32) -> Triangle {
+    Triangle::new(corner, size)
+}
+
+/// every coordinate whose [`OffsetCoord`] is in `0..width` and `0..height`, a rectangle on the
+/// screen when the layout fits the orientation.
+pub fn rectangle(layout: OffsetLayout, width: i32, height: i32) -> Rectangle {
+    Rectangle::new(layout, width, height)
+}
+
+/// builds a grid with an item at every coordinate, made by `fill`.
+pub fn fill<T, I, F>(coordinates: I, mut fill: F) -> HexGrid<T>
+where
+    I: IntoIterator<Item = Hex>,
+    F: FnMut(Hex) -> T,
+{
+    let mut grid = HexGrid::new();
+    for hex in coordinates {
+        grid.insert(hex, fill(hex));
+    }
+    grid
+}
+
+/// Iterates over the coordinates of [`hexagon`], in the same order as [`RangeCoordinates`].
+#[derive(Debug, Clone)]
+pub struct Hexagon {
+    iter: RangeCoordinates,
+}
+
+impl Hexagon {
+    pub fn new(radius: i32, center: impl Into<Hex>) -> Self {
+        Hexagon {
+            iter: RangeCoordinates::new(radius, center),
+        }
+    }
+}
+
+impl Iterator for Hexagon {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Hex::from)
+    }
+}
+
+/// Iterates over the coordinates of [`parallelogram`], column by column.
+#[derive(Debug, Clone)]
+pub struct Parallelogram {
+    min: Hex,
+    max: Hex,
+    current: Hex,
+}
+
+impl Parallelogram {
+    pub fn new(corner_a: impl Into<Hex>, corner_b: impl Into<Hex>) -> Self {
+        let corner_a = corner_a.into();
+        let corner_b = corner_b.into();
+        let min = Hex::new(corner_a.q.min(corner_b.q), corner_a.r.min(corner_b.r));
+        let max = Hex::new(corner_a.q.max(corner_b.q), corner_a.r.max(corner_b.r));
+
+        Parallelogram {
+            min,
+            max,
+            current: min,
+        }
+    }
+}
+
+impl Iterator for Parallelogram {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current;
+        if current.q > self.max.q {
+            return None;
+        }
+
+        self.current = if current.r < self.max.r {
+            Hex::new(current.q, current.r + 1)
+        } else {
+            Hex::new(current.q + 1, self.min.r)
+        };
+
+        Some(current)
+    }
+}
+
+/// Iterates over the coordinates of [`triangle`], column by column.
+#[derive(Debug, Clone)]
+pub struct Triangle {
+    corner: Hex,
+    size: i32,
+    current: (i32, i32),
+}
+
+impl Triangle {
+    pub fn new(corner: impl Into<Hex>, size: i32) -> Self {
+        Triangle {
+            corner: corner.into(),
+            size,
+            current: (0, 0),
+        }
+    }
+}
+
+impl Iterator for Triangle {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (q, r) = self.current;
+        if q > self.size {
+            return None;
+        }
+
+        self.current = if q + r < self.size {
+            (q, r + 1)
+        } else {
+            (q + 1, 0)
+        };
+
+        Some(self.corner + Hex::new(q, r))
+    }
+}
+
+/// Iterates over the coordinates of [`rectangle`], row by row.
+#[derive(Debug, Clone)]
+pub struct Rectangle {
+    layout: OffsetLayout,
+    width: i32,
+    height: i32,
+    current: OffsetCoord,
+}
+
+impl Rectangle {
+    pub fn new(layout: OffsetLayout, width: i32, height: i32) -> Self {
+        Rectangle {
+            layout,
+            width,
+            height,
+            current: OffsetCoord::new(0, 0),
+        }
+    }
+}
+
+impl Iterator for Rectangle {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current;
+        if self.width <= 0 || current.row >= self.height {
+            return None;
+        }
+
+        self.current = if current.col + 1 < self.width {
+            OffsetCoord::new(current.col + 1, current.row)
+        } else {
+            OffsetCoord::new(0, current.row + 1)
+        };
+
+        Some(current.to_hex(self.layout))
+    }
+}
+
+#[test]
+fn parallelogram_test() {
+    let out: Vec<_> = parallelogram((1, 0), (0, 1)).collect();
+    assert_eq!(out, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+
+    assert_eq!(parallelogram((-2, 3), (2, -1)).count(), 25);
+    assert_eq!(parallelogram((4, 4), (4, 4)).count(), 1);
+}
+
+#[test]
+fn triangle_test() {
+    let out: Vec<_> = triangle((1, 1), 1).collect();
+    assert_eq!(out, vec![(1, 1), (1, 2), (2, 1)]);
+
+    assert_eq!(triangle((0, 0), 3).count(), 10);
+    assert_eq!(triangle((0, 0), -1).count(), 0);
+    assert!(triangle((0, 0), 4).all(|hex| hex.length() <= 4));
+}
+
+#[test]
+fn rectangle_test() {
+    let out: Vec<_> = rectangle(OffsetLayout::OddR, 2, 2).collect();
+    assert_eq!(out, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+
+    let grid = fill(rectangle(OffsetLayout::EvenQ, 4, 3), |_| ());
+    let (_, rows) = grid.to_offset_rows(OffsetLayout::EvenQ);
+    assert_eq!(rows.len(), 3);
+    assert!(
+        rows.iter()
+            .all(|row| row.len() == 4 && row.iter().all(Option::is_some))
+    );
+
+    assert_eq!(rectangle(OffsetLayout::OddQ, 0, 5).count(), 0);
+}
+
+#[test]
+fn hexagon_test() {
+    let out: Vec<Hex> = hexagon(1, (0, 0)).collect();
+    assert_eq!(out.len(), 7);
+    assert_eq!(out[0], Hex::new(-1, 0));
+
+    let grid = fill(hexagon(2, (1, 1)), |hex| hex.distance((1, 1)));
+    assert_eq!(grid.iter_range(2, (1, 1)).count(), 19);
+    assert_eq!(grid.get(1, 1), Some(&0));
+    assert_eq!(grid.get(3, 1), Some(&2));
+    assert_eq!(grid.get(4, 1), None);
+}
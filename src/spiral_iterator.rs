@@ -0,0 +1,128 @@
+use crate::around_iterator::AroundIterator;
+use crate::{Hex, HexGrid, key};
+
+pub struct Values<'a, T> {
+    pub(crate) iter: SpiralIterator<'a, T>,
+}
+
+impl<'a, T> Iterator for Values<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, v)| v)
+    }
+}
+
+pub struct Keys<'a, T> {
+    pub(crate) iter: SpiralIterator<'a, T>,
+}
+
+impl<'a, T> Iterator for Keys<'a, T> {
+    type Item = &'a (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(k, _)| k)
+    }
+}
+
+/// Iterates over the present cells closest first: the center, then every ring of
+/// [`AroundIterator`] with an increasing radius up to and including `max_radius`.
+///
+/// Rings are only visited when the iterator gets to them, so stopping at the first match
+/// (with `find`, `position`, `take_while`, ...) does not look at the rest of the grid.
+#[derive(Debug)]
+pub struct SpiralIterator<'a, T> {
+    grid: &'a HexGrid<T>,
+    center: (i32, i32),
+    max_radius: i32,
+    radius: i32,
+    ring: AroundIterator<'a, T>,
+}
+
+impl<'a, T> SpiralIterator<'a, T> {
+    pub fn new(grid: &'a HexGrid<T>, center: impl Into<Hex>, max_radius: i32) -> Self {
+        let center = key(center);
+        SpiralIterator {
+            grid,
+            center,
+            max_radius,
+            radius: 0,
+            ring: AroundIterator::new(grid, 0, center),
+        }
+    }
+
+    /// the radius of the ring that is currently iterated.
+    pub fn radius(&self) -> i32 {
+        self.radius
+    }
+}
+
+impl<'a, T> Iterator for SpiralIterator<'a, T> {
+    type Item = (&'a (i32, i32), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.radius > self.max_radius {
+                return None;
+            }
+
+            if let Some(item) = self.ring.next() {
+                return Some(item);
+            }
+
+            self.radius += 1;
+            self.ring = AroundIterator::new(self.grid, self.radius, self.center);
+        }
+    }
+}
+
+#[cfg(test)]
+use collection_literals::btree;
+
+#[test]
+fn spiral_iterator_test() {
+    //
+    //     A B C
+    //      D
+    //   F E
+    let grid = HexGrid::from(btree! {
+        (0, 0) => "A",
+        (1, 0) => "B",
+        (2, 0) => "C",
+        (0, 1) => "D",
+        (-1, 2) => "E",
+        (-2, 2) => "F",
+    });
+
+    let out: Vec<_> = SpiralIterator::new(&grid, (0, 0), 0).collect();
+    assert_eq!(out, vec![(&(0, 0), &"A")]);
+
+    let out: Vec<_> = SpiralIterator::new(&grid, (0, 0), 2).collect();
+    assert_eq!(
+        out,
+        vec![
+            (&(0, 0), &"A"),
+            (&(1, 0), &"B"),
+            (&(0, 1), &"D"),
+            (&(2, 0), &"C"),
+            (&(-1, 2), &"E"),
+            (&(-2, 2), &"F"),
+        ]
+    );
+
+    let out: Vec<_> = SpiralIterator::new(&grid, (1, 1), 1).collect();
+    assert_eq!(out, vec![(&(0, 1), &"D"), (&(1, 0), &"B"), (&(2, 0), &"C")]);
+}
+
+#[test]
+fn spiral_iterator_stops_early() {
+    let grid = HexGrid::from(btree! {
+        (0, 0) => "A",
+        (1, 0) => "B",
+        (2, 0) => "C",
+    });
+
+    let mut iter = SpiralIterator::new(&grid, (0, 0), 10);
+    assert_eq!(iter.find(|(_, v)| **v == "B"), Some((&(1, 0), &"B")));
+    assert_eq!(iter.radius(), 1);
+}
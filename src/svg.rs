@@ -0,0 +1,265 @@
+use crate::{Hex, HexGrid, Layout};
+use std::fmt::Write;
+
+/// How a single cell is drawn by [`to_svg`], colors are anything SVG understands
+/// like `"green"` or `"#3a7d44"`.
+/// # Examples
+/// ```
+/// use hex_grid::svg::CellStyle;
+/// let style = CellStyle::new("#3a7d44").with_label("forest");
+///
+/// assert_eq!(style.fill, "#3a7d44");
+/// assert_eq!(style.stroke, "black");
+/// assert_eq!(style.label.as_deref(), Some("forest"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellStyle {
+    pub fill: String,
+    pub stroke: String,
+    /// text drawn in the middle of the cell.
+    pub label: Option<String>,
+}
+
+impl CellStyle {
+    pub fn new(fill: impl Into<String>) -> Self {
+        CellStyle {
+            fill: fill.into(),
+            ..CellStyle::default()
+        }
+    }
+
+    pub fn with_stroke(mut self, stroke: impl Into<String>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl Default for CellStyle {
+    fn default() -> Self {
+        CellStyle {
+            fill: "white".to_string(),
+            stroke: "black".to_string(),
+            label: None,
+        }
+    }
+}
+
+/// The extras of [`to_svg_with_options`] that are drawn on top of the cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    /// writes the `q,r` coordinate at the bottom of every cell.
+    pub coordinates: bool,
+    /// a line through the centers of these hexes, like the result of [`HexGrid::astar`]
+    /// mapped with [`Hex::from`].
+    pub path: Vec<Hex>,
+    pub path_stroke: String,
+    /// the space around the outermost cells.
+    pub margin: f64,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            coordinates: false,
+            path: Vec::new(),
+            path_stroke: "red".to_string(),
+            margin: 2.0,
+        }
+    }
+}
+
+/// draws every cell of the grid as a hex in a standalone SVG document, `style` decides the
+/// colors and the label of every cell.
+pub fn to_svg<T, F>(hexgrid: &HexGrid<T>, layout: Layout, style: F) -> String
+where
+    F: FnMut(Hex, &T) -> CellStyle,
+{
+    to_svg_with_options(hexgrid, layout, &SvgOptions::default(), style)
+}
+
+/// like [`to_svg`], with coordinates and a path drawn on top when asked for in `options`.
+pub fn to_svg_with_options<T, F>(
+    hexgrid: &HexGrid<T>,
+    layout: Layout,
+    options: &SvgOptions,
+    mut style: F,
+) -> String
+where
+    F: FnMut(Hex, &T) -> CellStyle,
+{
+    let corners: Vec<_> = hexgrid
+        .keys()
+        .map(|&key| Hex::from(key))
+        .chain(options.path.iter().copied())
+        .flat_map(|hex| layout.hex_corners(hex))
+        .collect();
+    let min_x = corners
+        .iter()
+        .map(|(x, _)| *x)
+        .fold(f64::INFINITY, f64::min);
+    let min_y = corners
+        .iter()
+        .map(|(_, y)| *y)
+        .fold(f64::INFINITY, f64::min);
+    let max_x = corners
+        .iter()
+        .map(|(x, _)| *x)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let max_y = corners
+        .iter()
+        .map(|(_, y)| *y)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (min_x, min_y, width, height) = if corners.is_empty() {
+        (0.0, 0.0, 0.0, 0.0)
+    } else {
+        (min_x, min_y, max_x - min_x, max_y - min_y)
+    };
+
+    let margin = options.margin;
+    let font_size = layout.size.0.min(layout.size.1) * 0.5;
+    let mut svg = String::new();
+
+    // writing to a String never fails
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{}" height="{}">"#,
+        number(min_x - margin),
+        number(min_y - margin),
+        number(width + 2.0 * margin),
+        number(height + 2.0 * margin),
+        number(width + 2.0 * margin),
+        number(height + 2.0 * margin),
+    );
+
+    for (&key, item) in hexgrid.iter() {
+        let hex = Hex::from(key);
+        let cell = style(hex, item);
+        let _ = writeln!(
+            svg,
+            r#"  <polygon points="{}" fill="{}" stroke="{}"/>"#,
+            points(layout.hex_corners(hex)),
+            escape(&cell.fill),
+            escape(&cell.stroke),
+        );
+
+        let (x, y) = layout.hex_to_pixel(hex);
+        if let Some(label) = &cell.label {
+            let _ = writeln!(
+                svg,
+                r#"  <text x="{}" y="{}" font-size="{}" text-anchor="middle" dominant-baseline="middle">{}</text>"#,
+                number(x),
+                number(y),
+                number(font_size),
+                escape(label),
+            );
+        }
+        if options.coordinates {
+            let _ = writeln!(
+                svg,
+                r#"  <text x="{}" y="{}" font-size="{}" text-anchor="middle" dominant-baseline="middle">{hex}</text>"#,
+                number(x),
+                number(y + layout.size.1 * 0.55),
+                number(font_size * 0.6),
+            );
+        }
+    }
+
+    if !options.path.is_empty() {
+        let _ = writeln!(
+            svg,
+            r#"  <polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+            points(options.path.iter().map(|hex| layout.hex_to_pixel(*hex))),
+            escape(&options.path_stroke),
+            number(font_size * 0.3),
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// a number with at most 2 decimals, without trailing zeros.
+fn number(value: f64) -> String {
+    let text = format!("{value:.2}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+fn points(points: impl IntoIterator<Item = (f64, f64)>) -> String {
+    points
+        .into_iter()
+        .map(|(x, y)| format!("{},{}", number(x), number(y)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+use crate::Orientation;
+#[cfg(test)]
+use collection_literals::btree;
+
+#[test]
+fn to_svg_test() {
+    let grid = HexGrid::from(btree! {
+        (0, 0) => "grass",
+        (1, 0) => "water",
+    });
+    let layout = Layout::new(Orientation::Flat, (10.0, 10.0), (0.0, 0.0));
+
+    let svg = to_svg(&grid, layout, |_, tile| match *tile {
+        "water" => CellStyle::new("blue"),
+        _ => CellStyle::new("green").with_label("<grass & 'moss'>"),
+    });
+
+    assert!(
+        svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-12 -10.66 39 29.98""#)
+    );
+    assert!(svg.ends_with("</svg>\n"));
+    assert_eq!(svg.matches("<polygon").count(), 2);
+    assert!(svg.contains(
+        r#"<polygon points="10,0 5,8.66 -5,8.66 -10,0 -5,-8.66 5,-8.66" fill="green" stroke="black"/>"#
+    ));
+    assert!(svg.contains(r#"fill="blue""#));
+    assert!(svg.contains(">&lt;grass &amp; &#39;moss&#39;&gt;</text>"));
+    assert!(!svg.contains("<polyline"));
+}
+
+#[test]
+fn to_svg_with_options_test() {
+    let grid = HexGrid::hexagon(1, (0, 0), |_| ());
+    let layout = Layout::new(Orientation::Pointy, (10.0, 10.0), (50.0, 50.0));
+    let options = SvgOptions {
+        coordinates: true,
+        path: vec![Hex::new(-1, 0), Hex::new(0, 0), Hex::new(1, -1)],
+        ..SvgOptions::default()
+    };
+
+    let svg = to_svg_with_options(&grid, layout, &options, |_, _| CellStyle::default());
+
+    assert_eq!(svg.matches("<text").count(), 7);
+    assert!(svg.contains(">-1,1</text>"));
+    assert!(svg.contains(r#"<polyline points="32.68,50 50,50 58.66,35" fill="none" stroke="red""#));
+
+    let empty = to_svg(&HexGrid::<()>::new(), layout, |_, _| CellStyle::default());
+    assert_eq!(
+        empty,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-2 -2 4 4\" width=\"4\" height=\"4\">\n</svg>\n"
+    );
+}
@@ -0,0 +1,821 @@
+//! Reads and writes hexagonal maps of the [Tiled](https://www.mapeditor.org) editor,
+//! only compiled with the `tiled` feature.
+//!
+//! Tiled stores hex maps as rows and columns, the stagger axis and stagger index of the map decide
+//! which [`OffsetLayout`] that is:
+//!
+//! | stagger axis | stagger index | layout                | orientation             |
+//! |--------------|---------------|-----------------------|-------------------------|
+//! | `y`          | `odd`         | [`OffsetLayout::OddR`]  | [`Orientation::Pointy`] |
+//! | `y`          | `even`        | [`OffsetLayout::EvenR`] | [`Orientation::Pointy`] |
+//! | `x`          | `odd`         | [`OffsetLayout::OddQ`]  | [`Orientation::Flat`]   |
+//! | `x`          | `even`        | [`OffsetLayout::EvenQ`] | [`Orientation::Flat`]   |
+//!
+//! The tile in column `x` and row `y` of a layer ends up at `OffsetCoord::new(x, y).to_hex(layout)`.
+
+use crate::{Hex, HexGrid, OffsetCoord, OffsetLayout, Orientation};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, XmlVersion};
+use serde_json::{Map, Value, json};
+use std::fmt;
+
+/// A global tile id as Tiled stores it, the highest bits say how the tile is flipped or rotated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TileId(pub u32);
+
+impl TileId {
+    const FLIPPED_HORIZONTALLY: u32 = 0x8000_0000;
+    const FLIPPED_VERTICALLY: u32 = 0x4000_0000;
+    const ROTATED_60: u32 = 0x2000_0000;
+    const ROTATED_120: u32 = 0x1000_0000;
+
+    /// the id of the tile in the tilesets, without the flip and rotation bits.
+    pub const fn gid(self) -> u32 {
+        self.0
+            & !(Self::FLIPPED_HORIZONTALLY
+                | Self::FLIPPED_VERTICALLY
+                | Self::ROTATED_60
+                | Self::ROTATED_120)
+    }
+
+    pub const fn flipped_horizontally(self) -> bool {
+        self.0 & Self::FLIPPED_HORIZONTALLY != 0
+    }
+
+    pub const fn flipped_vertically(self) -> bool {
+        self.0 & Self::FLIPPED_VERTICALLY != 0
+    }
+
+    pub const fn rotated_60(self) -> bool {
+        self.0 & Self::ROTATED_60 != 0
+    }
+
+    pub const fn rotated_120(self) -> bool {
+        self.0 & Self::ROTATED_120 != 0
+    }
+}
+
+/// A hexagonal Tiled map with one grid per tile layer.
+/// # Examples
+/// ```
+/// use hex_grid::tiled::{TileId, TiledMap};
+/// use hex_grid::OffsetLayout;
+/// let map = TiledMap::from_tmj(r#"{
+///     "orientation": "hexagonal", "staggeraxis": "y", "staggerindex": "odd",
+///     "width": 2, "height": 2, "tilewidth": 32, "tileheight": 28, "hexsidelength": 14,
+///     "layers": [{ "type": "tilelayer", "name": "ground", "width": 2, "height": 2, "data": [1, 2, 0, 3] }],
+///     "tilesets": [{ "firstgid": 1, "source": "terrain.tsj" }]
+/// }"#).unwrap();
+///
+/// assert_eq!(map.layout, OffsetLayout::OddR);
+/// let ground = map.layer("ground").unwrap();
+/// assert_eq!(ground.get(1, 0), Some(&TileId(2)));
+/// assert_eq!(ground.get(0, 1), None);
+/// assert_eq!(ground.get(1, 1), Some(&TileId(3)));
+///
+/// assert_eq!(TiledMap::from_tmj(&map.to_tmj().unwrap()), Ok(map));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct TiledMap {
+    pub layout: OffsetLayout,
+    /// the number of columns, [`TiledMap::to_tmj`] fails for tiles outside of `0..width`.
+    pub width: u32,
+    /// the number of rows, [`TiledMap::to_tmj`] fails for tiles outside of `0..height`.
+    pub height: u32,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub hex_side_length: u32,
+    /// the tile layers in the order Tiled draws them, layers inside groups included.
+    pub layers: Vec<TiledLayer>,
+    /// the tilesets as Tiled JSON, they are written back unchanged.
+    pub tilesets: Vec<Value>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TiledLayer {
+    pub name: String,
+    /// every tile of the layer, empty tiles are left out.
+    pub tiles: HexGrid<TileId>,
+}
+
+/// returned when a Tiled map can't be read or written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TiledError {
+    /// the text isn't valid JSON or XML, or the JSON couldn't be written.
+    Syntax(String),
+    /// a property that every hexagonal map has is missing or has the wrong type.
+    Missing(&'static str),
+    /// a property has a value that doesn't make sense.
+    Invalid(String),
+    /// the map uses something this importer doesn't read, like compressed layers or infinite maps.
+    Unsupported(String),
+}
+
+impl fmt::Display for TiledError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TiledError::Syntax(message) => write!(f, "invalid Tiled map: {message}"),
+            TiledError::Missing(property) => {
+                write!(f, "invalid Tiled map: missing property `{property}`")
+            }
+            TiledError::Invalid(message) => write!(f, "invalid Tiled map: {message}"),
+            TiledError::Unsupported(message) => write!(f, "unsupported Tiled map: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for TiledError {}
+
+impl TiledMap {
+    /// reads a map saved as Tiled JSON (`.tmj` or `.json`).
+    pub fn from_tmj(text: &str) -> Result<Self, TiledError> {
+        let map: Value =
+            serde_json::from_str(text).map_err(|error| TiledError::Syntax(error.to_string()))?;
+
+        let orientation = map["orientation"]
+            .as_str()
+            .ok_or(TiledError::Missing("orientation"))?;
+        check_orientation(orientation)?;
+        if map["infinite"].as_bool() == Some(true) {
+            return Err(TiledError::Unsupported("infinite maps".to_string()));
+        }
+
+        let stagger_axis = map["staggeraxis"]
+            .as_str()
+            .ok_or(TiledError::Missing("staggeraxis"))?;
+        let stagger_index = map["staggerindex"]
+            .as_str()
+            .ok_or(TiledError::Missing("staggerindex"))?;
+        let layout = stagger_layout(stagger_axis, stagger_index)?;
+        let width = json_u32(&map, "width")?;
+        let height = json_u32(&map, "height")?;
+
+        let mut layers = Vec::new();
+        read_json_layers(&map["layers"], layout, (width, height), &mut layers)?;
+
+        Ok(TiledMap {
+            layout,
+            width,
+            height,
+            tile_width: json_u32(&map, "tilewidth")?,
+            tile_height: json_u32(&map, "tileheight")?,
+            hex_side_length: json_u32(&map, "hexsidelength")?,
+            layers,
+            tilesets: map["tilesets"].as_array().cloned().unwrap_or_default(),
+        })
+    }
+
+    /// reads a map saved as Tiled XML (`.tmx`), with layers stored as CSV, uncompressed base64
+    /// or XML tiles. Tilesets are converted to the JSON form with their attributes and image.
+    pub fn from_tmx(text: &str) -> Result<Self, TiledError> {
+        let mut reader = Reader::from_str(text);
+        let mut map: Option<TiledMap> = None;
+        let mut tileset: Option<Map<String, Value>> = None;
+        let mut layer: Option<(String, u32, u32)> = None;
+        let mut data: Option<LayerData> = None;
+
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|error| TiledError::Syntax(error.to_string()))?;
+            let is_start = matches!(event, Event::Start(_));
+
+            match event {
+                Event::Start(element) | Event::Empty(element) => {
+                    let attributes = xml_attributes(&element)?;
+                    match element.name().as_ref() {
+                        "map" => map = Some(tmx_map(&attributes)?),
+                        "tileset" => {
+                            let tileset_json = attributes
+                                .iter()
+                                .map(|(key, value)| (key.clone(), xml_value(value)))
+                                .collect();
+                            if is_start {
+                                tileset = Some(tileset_json);
+                            } else {
+                                tmx_map_mut(&mut map)?
+                                    .tilesets
+                                    .push(Value::Object(tileset_json));
+                            }
+                        }
+                        "image" => {
+                            if let Some(tileset) = &mut tileset {
+                                for (key, json_key) in [
+                                    ("source", "image"),
+                                    ("width", "imagewidth"),
+                                    ("height", "imageheight"),
+                                    ("trans", "transparentcolor"),
+                                ] {
+                                    if let Some(value) = xml_attribute(&attributes, key) {
+                                        tileset.insert(json_key.to_string(), xml_value(value));
+                                    }
+                                }
+                            }
+                        }
+                        "layer" => {
+                            let map = tmx_map_mut(&mut map)?;
+                            let name = xml_attribute(&attributes, "name").unwrap_or_default();
+                            let width = xml_u32(&attributes, "width")?.unwrap_or(map.width);
+                            let height = xml_u32(&attributes, "height")?.unwrap_or(map.height);
+                            layer = Some((name.to_string(), width, height));
+                        }
+                        "data" => {
+                            if let Some(compression) = xml_attribute(&attributes, "compression") {
+                                return Err(TiledError::Unsupported(format!(
+                                    "{compression} compressed layers"
+                                )));
+                            }
+                            data = Some(LayerData {
+                                encoding: xml_attribute(&attributes, "encoding")
+                                    .map(str::to_string),
+                                text: String::new(),
+                                tiles: Vec::new(),
+                            });
+                        }
+                        "tile" => {
+                            if let Some(data) = &mut data {
+                                data.tiles.push(xml_u32(&attributes, "gid")?.unwrap_or(0));
+                            }
+                        }
+                        "chunk" => {
+                            return Err(TiledError::Unsupported("infinite maps".to_string()));
+                        }
+                        _ => {}
+                    }
+
+                    // an empty `<layer/>` has no data to wait for
+                    if !is_start && element.name().as_ref() == "layer" {
+                        let (name, width, height) = layer.take().unwrap_or_default();
+                        let map = tmx_map_mut(&mut map)?;
+                        let tiles = tiles_from_gids(&name, Vec::new(), width, height, map.layout)?;
+                        map.layers.push(TiledLayer { name, tiles });
+                    }
+                }
+                Event::Text(text) => {
+                    if let Some(data) = &mut data {
+                        data.text.push_str(&text.xml_content(XmlVersion::default()));
+                    }
+                }
+                Event::End(element) => match element.name().as_ref() {
+                    "tileset" => {
+                        if let Some(tileset) = tileset.take() {
+                            tmx_map_mut(&mut map)?.tilesets.push(Value::Object(tileset));
+                        }
+                    }
+                    "layer" => {
+                        let (name, width, height) = layer.take().unwrap_or_default();
+                        let gids = match data.take() {
+                            Some(data) => data.gids()?,
+                            None => Vec::new(),
+                        };
+                        let map = tmx_map_mut(&mut map)?;
+                        let tiles = tiles_from_gids(&name, gids, width, height, map.layout)?;
+                        map.layers.push(TiledLayer { name, tiles });
+                    }
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        map.ok_or(TiledError::Missing("map"))
+    }
+
+    /// writes the map as Tiled JSON, which Tiled opens as a `.tmj` file.
+    ///
+    /// Returns [`TiledError::Invalid`] when a layer has a tile outside of the `width` and `height`
+    /// of the map, Tiled has no place for it.
+    pub fn to_tmj(&self) -> Result<String, TiledError> {
+        let (stagger_axis, stagger_index) = match self.layout {
+            OffsetLayout::OddR => ("y", "odd"),
+            OffsetLayout::EvenR => ("y", "even"),
+            OffsetLayout::OddQ => ("x", "odd"),
+            OffsetLayout::EvenQ => ("x", "even"),
+        };
+
+        for layer in &self.layers {
+            for key in layer.tiles.keys() {
+                let offset = Hex::from(*key).to_offset(self.layout);
+                if !(0..self.width as i32).contains(&offset.col)
+                    || !(0..self.height as i32).contains(&offset.row)
+                {
+                    return Err(TiledError::Invalid(format!(
+                        "layer `{}` has a tile at column {} and row {}, outside the {} * {} map",
+                        layer.name, offset.col, offset.row, self.width, self.height
+                    )));
+                }
+            }
+        }
+
+        let layers: Vec<_> = self
+            .layers
+            .iter()
+            .enumerate()
+            .map(|(index, layer)| {
+                let data: Vec<_> = (0..self.height as i32)
+                    .flat_map(|y| (0..self.width as i32).map(move |x| OffsetCoord::new(x, y)))
+                    .map(|offset| {
+                        layer
+                            .tiles
+                            .get_by_point(offset.to_hex(self.layout))
+                            .map_or(0, |tile| tile.0)
+                    })
+                    .collect();
+
+                json!({
+                    "id": index + 1,
+                    "name": layer.name,
+                    "type": "tilelayer",
+                    "x": 0,
+                    "y": 0,
+                    "width": self.width,
+                    "height": self.height,
+                    "opacity": 1,
+                    "visible": true,
+                    "data": data,
+                })
+            })
+            .collect();
+
+        let map = json!({
+            "type": "map",
+            "version": "1.10",
+            "orientation": "hexagonal",
+            "renderorder": "right-down",
+            "infinite": false,
+            "width": self.width,
+            "height": self.height,
+            "tilewidth": self.tile_width,
+            "tileheight": self.tile_height,
+            "hexsidelength": self.hex_side_length,
+            "staggeraxis": stagger_axis,
+            "staggerindex": stagger_index,
+            "nextlayerid": self.layers.len() + 1,
+            "nextobjectid": 1,
+            "layers": layers,
+            "tilesets": self.tilesets,
+        });
+
+        serde_json::to_string_pretty(&map).map_err(|error| TiledError::Syntax(error.to_string()))
+    }
+
+    /// how the hexes point, rows are staggered for pointy hexes and columns for flat hexes.
+    pub fn orientation(&self) -> Orientation {
+        match self.layout {
+            OffsetLayout::OddR | OffsetLayout::EvenR => Orientation::Pointy,
+            OffsetLayout::OddQ | OffsetLayout::EvenQ => Orientation::Flat,
+        }
+    }
+
+    /// the tiles of the first layer with this name.
+    pub fn layer(&self, name: &str) -> Option<&HexGrid<TileId>> {
+        self.layers
+            .iter()
+            .find(|layer| layer.name == name)
+            .map(|layer| &layer.tiles)
+    }
+}
+
+fn check_orientation(orientation: &str) -> Result<(), TiledError> {
+    if orientation != "hexagonal" {
+        return Err(TiledError::Unsupported(format!("{orientation} maps")));
+    }
+    Ok(())
+}
+
+fn stagger_layout(stagger_axis: &str, stagger_index: &str) -> Result<OffsetLayout, TiledError> {
+    match (stagger_axis, stagger_index) {
+        ("y", "odd") => Ok(OffsetLayout::OddR),
+        ("y", "even") => Ok(OffsetLayout::EvenR),
+        ("x", "odd") => Ok(OffsetLayout::OddQ),
+        ("x", "even") => Ok(OffsetLayout::EvenQ),
+        _ => Err(TiledError::Invalid(format!(
+            "stagger axis `{stagger_axis}` with stagger index `{stagger_index}`"
+        ))),
+    }
+}
+
+/// puts the gids of a layer, row by row, on the grid, 0 is an empty tile.
+fn tiles_from_gids(
+    name: &str,
+    gids: Vec<u32>,
+    width: u32,
+    height: u32,
+    layout: OffsetLayout,
+) -> Result<HexGrid<TileId>, TiledError> {
+    if gids.len() as u64 != width as u64 * height as u64 {
+        return Err(TiledError::Invalid(format!(
+            "layer `{name}` has {} tiles, expected {width} * {height}",
+            gids.len()
+        )));
+    }
+
+    let mut tiles = HexGrid::new();
+    for (index, gid) in gids.into_iter().enumerate() {
+        if gid != 0 {
+            let offset = OffsetCoord::new(
+                (index % width as usize) as i32,
+                (index / width as usize) as i32,
+            );
+            tiles.insert(offset.to_hex(layout), TileId(gid));
+        }
+    }
+    Ok(tiles)
+}
+
+fn json_u32(object: &Value, key: &'static str) -> Result<u32, TiledError> {
+    object[key]
+        .as_u64()
+        .and_then(|value| u32::try_from(value).ok())
+        .ok_or(TiledError::Missing(key))
+}
+
+fn read_json_layers(
+    layers: &Value,
+    layout: OffsetLayout,
+    map_size: (u32, u32),
+    out: &mut Vec<TiledLayer>,
+) -> Result<(), TiledError> {
+    let Some(layers) = layers.as_array() else {
+        return Ok(());
+    };
+
+    for layer in layers {
+        match layer["type"].as_str() {
+            Some("tilelayer") => {
+                let name = layer["name"].as_str().unwrap_or_default().to_string();
+                if layer.get("chunks").is_some() {
+                    return Err(TiledError::Unsupported("infinite maps".to_string()));
+                }
+                let width = json_u32(layer, "width").unwrap_or(map_size.0);
+                let height = json_u32(layer, "height").unwrap_or(map_size.1);
+
+                let gids = match &layer["data"] {
+                    Value::Array(values) => values
+                        .iter()
+                        .map(|value| {
+                            value
+                                .as_u64()
+                                .and_then(|gid| u32::try_from(gid).ok())
+                                .ok_or_else(|| {
+                                    TiledError::Invalid(format!("tile {value} in layer `{name}`"))
+                                })
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                    Value::String(text) => {
+                        if let Some(compression) = layer["compression"]
+                            .as_str()
+                            .filter(|compression| !compression.is_empty())
+                        {
+                            return Err(TiledError::Unsupported(format!(
+                                "{compression} compressed layers"
+                            )));
+                        }
+                        LayerData {
+                            encoding: layer["encoding"].as_str().map(str::to_string),
+                            text: text.clone(),
+                            tiles: Vec::new(),
+                        }
+                        .gids()?
+                    }
+                    _ => return Err(TiledError::Missing("data")),
+                };
+
+                let tiles = tiles_from_gids(&name, gids, width, height, layout)?;
+                out.push(TiledLayer { name, tiles });
+            }
+            Some("group") => read_json_layers(&layer["layers"], layout, map_size, out)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// the tiles of a layer as they are stored in the file.
+struct LayerData {
+    encoding: Option<String>,
+    text: String,
+    /// the gids of `<tile>` elements, when there is no encoding.
+    tiles: Vec<u32>,
+}
+
+impl LayerData {
+    fn gids(self) -> Result<Vec<u32>, TiledError> {
+        match self.encoding.as_deref() {
+            None => Ok(self.tiles),
+            Some("csv") => self
+                .text
+                .split(',')
+                .map(str::trim)
+                .filter(|gid| !gid.is_empty())
+                .map(|gid| {
+                    gid.parse()
+                        .map_err(|_| TiledError::Invalid(format!("tile `{gid}`")))
+                })
+                .collect(),
+            Some("base64") => {
+                let bytes = decode_base64(&self.text)?;
+                if bytes.len() % 4 != 0 {
+                    return Err(TiledError::Invalid(
+                        "base64 layer data is not a multiple of 4 bytes".to_string(),
+                    ));
+                }
+                Ok(bytes
+                    .chunks_exact(4)
+                    .map(|gid| u32::from_le_bytes([gid[0], gid[1], gid[2], gid[3]]))
+                    .collect())
+            }
+            Some(encoding) => Err(TiledError::Unsupported(format!("{encoding} encoding"))),
+        }
+    }
+}
+
+fn decode_base64(text: &str) -> Result<Vec<u8>, TiledError> {
+    let mut bytes = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for character in text
+        .bytes()
+        .filter(|character| !character.is_ascii_whitespace())
+    {
+        let value = match character {
+            b'A'..=b'Z' => character - b'A',
+            b'a'..=b'z' => character - b'a' + 26,
+            b'0'..=b'9' => character - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            _ => {
+                return Err(TiledError::Invalid(format!(
+                    "`{}` in base64 layer data",
+                    character as char
+                )));
+            }
+        };
+
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(bytes)
+}
+
+fn tmx_map(attributes: &[(String, String)]) -> Result<TiledMap, TiledError> {
+    let orientation =
+        xml_attribute(attributes, "orientation").ok_or(TiledError::Missing("orientation"))?;
+    check_orientation(orientation)?;
+    if xml_attribute(attributes, "infinite") == Some("1") {
+        return Err(TiledError::Unsupported("infinite maps".to_string()));
+    }
+
+    let required = |key| xml_u32(attributes, key)?.ok_or(TiledError::Missing(key));
+    let layout = stagger_layout(
+        xml_attribute(attributes, "staggeraxis").ok_or(TiledError::Missing("staggeraxis"))?,
+        xml_attribute(attributes, "staggerindex").ok_or(TiledError::Missing("staggerindex"))?,
+    )?;
+
+    Ok(TiledMap {
+        layout,
+        width: required("width")?,
+        height: required("height")?,
+        tile_width: required("tilewidth")?,
+        tile_height: required("tileheight")?,
+        hex_side_length: required("hexsidelength")?,
+        layers: Vec::new(),
+        tilesets: Vec::new(),
+    })
+}
+
+fn tmx_map_mut(map: &mut Option<TiledMap>) -> Result<&mut TiledMap, TiledError> {
+    map.as_mut().ok_or(TiledError::Missing("map"))
+}
+
+fn xml_attributes(element: &BytesStart<'_>) -> Result<Vec<(String, String)>, TiledError> {
+    element
+        .attributes()
+        .map(|attribute| {
+            let attribute = attribute.map_err(|error| TiledError::Syntax(error.to_string()))?;
+            let value = attribute
+                .normalized_value(XmlVersion::default())
+                .map_err(|error| TiledError::Syntax(error.to_string()))?;
+            Ok((attribute.key.as_ref().to_string(), value.into_owned()))
+        })
+        .collect()
+}
+
+fn xml_attribute<'a>(attributes: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.as_str())
+}
+
+fn xml_u32(attributes: &[(String, String)], key: &'static str) -> Result<Option<u32>, TiledError> {
+    xml_attribute(attributes, key)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| TiledError::Invalid(format!("`{key}` is `{value}`")))
+        })
+        .transpose()
+}
+
+/// attributes are all text in XML, numbers become JSON numbers like in a `.tmj` file.
+fn xml_value(value: &str) -> Value {
+    match value.parse::<i64>() {
+        Ok(number) => json!(number),
+        Err(_) => json!(value),
+    }
+}
+
+#[cfg(test)]
+const TMX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="hexagonal" renderorder="right-down" width="3" height="2" tilewidth="28" tileheight="32" hexsidelength="16" staggeraxis="x" staggerindex="even" infinite="0">
+ <tileset firstgid="1" source="terrain.tsx"/>
+ <tileset firstgid="10" name="units" tilewidth="28" tileheight="32" tilecount="4" columns="2">
+  <image source="units.png" width="56" height="64"/>
+ </tileset>
+ <layer id="1" name="ground" width="3" height="2">
+  <data encoding="csv">
+1,2,3,
+4,0,2147483654
+</data>
+ </layer>
+ <group id="3" name="things">
+  <layer id="2" name="units" width="3" height="2">
+   <data encoding="base64">
+   CgAAAAAAAAAAAAAAAAAAAAAAAAALAAAA
+   </data>
+  </layer>
+ </group>
+</map>
+"#;
+
+#[test]
+fn tmx_test() {
+    let map = TiledMap::from_tmx(TMX).unwrap();
+
+    assert_eq!(map.layout, OffsetLayout::EvenQ);
+    assert_eq!(map.orientation(), Orientation::Flat);
+    assert_eq!((map.width, map.height), (3, 2));
+    assert_eq!(map.hex_side_length, 16);
+
+    // even columns are shoved down, so the first row runs up and down
+    let ground = map.layer("ground").unwrap();
+    assert_eq!(ground.len(), 5);
+    assert_eq!(ground.get(0, 0), Some(&TileId(1)));
+    assert_eq!(ground.get(1, -1), Some(&TileId(2)));
+    assert_eq!(ground.get(2, -1), Some(&TileId(3)));
+    assert_eq!(ground.get(0, 1), Some(&TileId(4)));
+    assert_eq!(ground.get(1, 0), None);
+
+    let flipped = ground.get(2, 0).unwrap();
+    assert_eq!(flipped.gid(), 6);
+    assert!(flipped.flipped_horizontally());
+    assert!(!flipped.flipped_vertically() && !flipped.rotated_60() && !flipped.rotated_120());
+
+    let units = map.layer("units").unwrap();
+    assert_eq!(
+        units.iter().collect::<Vec<_>>(),
+        vec![(&(0, 0), &TileId(10)), (&(2, 0), &TileId(11))]
+    );
+
+    assert_eq!(
+        map.tilesets,
+        vec![
+            json!({ "firstgid": 1, "source": "terrain.tsx" }),
+            json!({
+                "firstgid": 10, "name": "units", "tilewidth": 28, "tileheight": 32,
+                "tilecount": 4, "columns": 2,
+                "image": "units.png", "imagewidth": 56, "imageheight": 64,
+            }),
+        ]
+    );
+}
+
+#[test]
+fn tmj_round_trip_test() {
+    let map = TiledMap::from_tmx(TMX).unwrap();
+    let tmj = map.to_tmj().unwrap();
+    let read_back = TiledMap::from_tmj(&tmj).unwrap();
+
+    assert_eq!(read_back, map);
+
+    let json: Value = serde_json::from_str(&tmj).unwrap();
+    assert_eq!(json["staggeraxis"], "x");
+    assert_eq!(json["staggerindex"], "even");
+    assert_eq!(
+        json["layers"][0]["data"],
+        json!([1, 2, 3, 4, 0, 2147483654u32])
+    );
+    assert_eq!(json["layers"][1]["data"], json!([10, 0, 0, 0, 0, 11]));
+}
+
+#[test]
+fn to_tmj_outside_map_test() {
+    let mut map = TiledMap::from_tmx(TMX).unwrap();
+    let layout = map.layout;
+    map.layers[1]
+        .tiles
+        .insert(OffsetCoord::new(-1, 1).to_hex(layout), TileId(12));
+
+    assert_eq!(
+        map.to_tmj(),
+        Err(TiledError::Invalid(
+            "layer `units` has a tile at column -1 and row 1, outside the 3 * 2 map".to_string()
+        ))
+    );
+
+    map.layers[1]
+        .tiles
+        .remove(OffsetCoord::new(-1, 1).to_hex(layout));
+    map.layers[0]
+        .tiles
+        .insert(OffsetCoord::new(0, 2).to_hex(layout), TileId(1));
+
+    assert!(matches!(map.to_tmj(), Err(TiledError::Invalid(_))));
+}
+
+#[test]
+fn stagger_layout_test() {
+    for (axis, index, layout) in [
+        ("y", "odd", OffsetLayout::OddR),
+        ("y", "even", OffsetLayout::EvenR),
+        ("x", "odd", OffsetLayout::OddQ),
+        ("x", "even", OffsetLayout::EvenQ),
+    ] {
+        let tmj = format!(
+            r#"{{"orientation": "hexagonal", "staggeraxis": "{axis}", "staggerindex": "{index}",
+                "width": 2, "height": 2, "tilewidth": 1, "tileheight": 1, "hexsidelength": 1,
+                "layers": [{{"type": "tilelayer", "name": "a", "data": [1, 2, 3, 4]}}]}}"#
+        );
+        let map = TiledMap::from_tmj(&tmj).unwrap();
+        assert_eq!(map.layout, layout);
+
+        let tiles = map.layer("a").unwrap();
+        for (gid, (x, y)) in [(1, (0, 0)), (2, (1, 0)), (3, (0, 1)), (4, (1, 1))] {
+            let hex = OffsetCoord::new(x, y).to_hex(layout);
+            assert_eq!(tiles.get_by_point(hex), Some(&TileId(gid)), "{layout:?}");
+        }
+    }
+}
+
+#[test]
+fn tiled_errors_test() {
+    let map = |extra: &str| {
+        format!(
+            r#"{{"orientation": "hexagonal", "staggeraxis": "y", "staggerindex": "odd",
+                "width": 2, "height": 1, "tilewidth": 1, "tileheight": 1, "hexsidelength": 1 {extra}}}"#
+        )
+    };
+
+    assert!(TiledMap::from_tmj(&map("")).is_ok());
+    assert!(matches!(
+        TiledMap::from_tmj("{"),
+        Err(TiledError::Syntax(_))
+    ));
+    assert_eq!(
+        TiledMap::from_tmj(&map(r#", "orientation": "isometric""#)),
+        Err(TiledError::Unsupported("isometric maps".to_string()))
+    );
+    assert_eq!(
+        TiledMap::from_tmj(&map(r#", "infinite": true"#)),
+        Err(TiledError::Unsupported("infinite maps".to_string()))
+    );
+    assert_eq!(
+        TiledMap::from_tmj(&map(
+            r#", "layers": [{"type": "tilelayer", "name": "a", "data": [1]}]"#
+        )),
+        Err(TiledError::Invalid(
+            "layer `a` has 1 tiles, expected 2 * 1".to_string()
+        ))
+    );
+    assert_eq!(
+        TiledMap::from_tmj(&map(
+            r#", "layers": [{"type": "tilelayer", "name": "a", "encoding": "base64", "compression": "zlib", "data": "eJw="}]"#
+        )),
+        Err(TiledError::Unsupported(
+            "zlib compressed layers".to_string()
+        ))
+    );
+    assert_eq!(
+        TiledMap::from_tmj(r#"{"orientation": "hexagonal"}"#),
+        Err(TiledError::Missing("staggeraxis"))
+    );
+    assert_eq!(
+        TiledMap::from_tmx("<map/>").unwrap_err().to_string(),
+        "invalid Tiled map: missing property `orientation`"
+    );
+}
+
+#[test]
+fn base64_test() {
+    assert_eq!(decode_base64("AQIDBA==").unwrap(), vec![1, 2, 3, 4]);
+    assert_eq!(decode_base64(" AQID\n").unwrap(), vec![1, 2, 3]);
+    assert!(decode_base64("AQ!D").is_err());
+}
@@ -0,0 +1,85 @@
+use crate::{Hex, HexGrid};
+
+/// The three axes through the origin of the cube coordinates, a reflection across an axis keeps
+/// that coordinate and swaps the other two.
+///
+/// In the pointy layout of [`HexGrid`] `Axis::R` keeps every item in its row and mirrors
+/// the grid from left to right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    Q,
+    R,
+    S,
+}
+
+/// copies the grid with every item turned around `center` by `steps` times 60 degrees,
+/// see [`Hex::rotate_around`].
+pub fn rotated<T: Clone>(hexgrid: &HexGrid<T>, center: impl Into<Hex>, steps: i32) -> HexGrid<T> {
+    let center = center.into();
+    hexgrid
+        .data
+        .iter()
+        .map(|(hex, item)| (Hex::from(*hex).rotate_around(center, steps), item.clone()))
+        .collect()
+}
+
+/// copies the grid with every item mirrored across `axis`, see [`Hex::reflect`].
+pub fn reflected<T: Clone>(hexgrid: &HexGrid<T>, axis: Axis) -> HexGrid<T> {
+    hexgrid
+        .data
+        .iter()
+        .map(|(hex, item)| (Hex::from(*hex).reflect(axis), item.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+use collection_literals::btree;
+
+#[test]
+fn rotated_test() {
+    //  A B
+    //   C
+    let grid = HexGrid::from(btree! {
+        (0, 0) => "A",
+        (1, 0) => "B",
+        (0, 1) => "C",
+    });
+
+    assert_eq!(
+        rotated(&grid, (0, 0), 1),
+        HexGrid::from(btree! {
+            (0, 0) => "A",
+            (0, 1) => "B",
+            (-1, 1) => "C",
+        })
+    );
+    assert_eq!(
+        rotated(&grid, (1, 0), -2),
+        HexGrid::from(btree! {
+            (1, 1) => "A",
+            (1, 0) => "B",
+            (2, 0) => "C",
+        })
+    );
+    assert_eq!(rotated(&grid, (5, -3), 6), grid);
+}
+
+#[test]
+fn reflected_test() {
+    let grid = HexGrid::from(btree! {
+        (0, 0) => "A",
+        (1, 0) => "B",
+        (0, 1) => "C",
+    });
+
+    assert_eq!(
+        reflected(&grid, Axis::R),
+        HexGrid::from(btree! {
+            (0, 0) => "A",
+            (-1, 0) => "B",
+            (-1, 1) => "C",
+        })
+    );
+    assert_eq!(reflected(&reflected(&grid, Axis::S), Axis::S), grid);
+}
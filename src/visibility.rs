@@ -0,0 +1,123 @@
+use crate::line_iterator::{NUDGE, line_nudged};
+use crate::{Hex, HexGrid};
+use std::collections::BTreeSet;
+
+/// checks whether `point_b` can be seen from `point_a`.
+///
+/// Every cell strictly between the two points is checked with `blocks`, the points themselves never
+/// block, so a wall can be seen but not be seen through. Cells without an item don't block.
+///
+/// When the line passes exactly between two hexes, sight is granted if either side is clear.
+/// This is done by drawing the line nudged to both sides, which also makes the check symmetric:
+/// `a` sees `b` exactly when `b` sees `a`.
+pub fn has_line_of_sight<T, F>(
+    hexgrid: &HexGrid<T>,
+    point_a: impl Into<Hex>,
+    point_b: impl Into<Hex>,
+    blocks: F,
+) -> bool
+where
+    F: Fn(&T) -> bool,
+{
+    let point_a = point_a.into();
+    let point_b = point_b.into();
+    let is_clear = |points: Vec<Hex>| {
+        // the first and last point are the viewer and the target
+        let mut inner = points.iter().skip(1).take(points.len().saturating_sub(2));
+        !inner.any(|point| hexgrid.get_by_point(point).is_some_and(&blocks))
+    };
+
+    is_clear(line_nudged(point_a, point_b, NUDGE))
+        || is_clear(line_nudged(point_a, point_b, -NUDGE))
+}
+
+/// collects every present cell within `radius` of `origin` that can be seen from `origin`,
+/// using the same rules as [`has_line_of_sight`]. The origin itself is included when present.
+pub fn field_of_view<T, F>(
+    hexgrid: &HexGrid<T>,
+    origin: impl Into<Hex>,
+    radius: i32,
+    blocks: F,
+) -> BTreeSet<(i32, i32)>
+where
+    F: Fn(&T) -> bool,
+{
+    let origin = origin.into();
+    hexgrid
+        .keys_range(radius, origin)
+        .filter(|point| has_line_of_sight(hexgrid, origin, **point, &blocks))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+use collection_literals::btree;
+
+#[cfg(test)]
+fn open_field(radius: i32, walls: &[(i32, i32)]) -> HexGrid<bool> {
+    let mut grid = HexGrid::new();
+    for point in crate::range_iterator::RangeCoordinates::new(radius, (0, 0)) {
+        grid.insert(point, walls.contains(&point));
+    }
+    grid
+}
+
+#[test]
+fn line_of_sight_test() {
+    let grid = open_field(4, &[(1, 0)]);
+    let blocks = |wall: &bool| *wall;
+
+    assert!(has_line_of_sight(&grid, (0, 0), (0, 0), blocks));
+    assert!(has_line_of_sight(&grid, (0, 0), (1, 0), blocks));
+    assert!(!has_line_of_sight(&grid, (0, 0), (2, 0), blocks));
+    assert!(!has_line_of_sight(&grid, (0, 0), (4, 0), blocks));
+    assert!(has_line_of_sight(&grid, (0, 0), (0, 3), blocks));
+    assert!(has_line_of_sight(&grid, (-2, 0), (-4, 2), blocks));
+}
+
+#[test]
+fn line_of_sight_edge_grazing() {
+    // the line from (0, 0) to (1, 1) runs exactly between (1, 0) and (0, 1)
+    let one_side = open_field(2, &[(1, 0)]);
+    let other_side = open_field(2, &[(0, 1)]);
+    let both_sides = open_field(2, &[(1, 0), (0, 1)]);
+    let blocks = |wall: &bool| *wall;
+
+    assert!(has_line_of_sight(&one_side, (0, 0), (1, 1), blocks));
+    assert!(has_line_of_sight(&other_side, (0, 0), (1, 1), blocks));
+    assert!(!has_line_of_sight(&both_sides, (0, 0), (1, 1), blocks));
+}
+
+#[test]
+fn line_of_sight_is_symmetric() {
+    let grid = open_field(4, &[(1, 0), (-1, 2), (0, -2), (2, 1)]);
+    let blocks = |wall: &bool| *wall;
+
+    let points: Vec<_> = grid.keys_range(4, (0, 0)).copied().collect();
+    for a in &points {
+        for b in &points {
+            assert_eq!(
+                has_line_of_sight(&grid, *a, *b, blocks),
+                has_line_of_sight(&grid, *b, *a, blocks),
+                "{a:?} {b:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn field_of_view_test() {
+    //  . # . .
+    let grid = HexGrid::from(btree! {
+        (0, 0) => false,
+        (1, 0) => true,
+        (2, 0) => false,
+        (3, 0) => false,
+        (0, 1) => false,
+    });
+
+    let visible = field_of_view(&grid, (0, 0), 2, |wall| *wall);
+
+    let expected = BTreeSet::from([(0, 0), (1, 0), (0, 1)]);
+    assert_eq!(expected, visible);
+}